@@ -0,0 +1,162 @@
+//! Difficulty of a piece of code, scored from how it types rather than what it
+//! does: its length, how much of it is symbols, how deeply brackets nest, how
+//! often Shift is needed and how long the identifiers are. Each measure is
+//! scaled to 0–1 between an "easy" and a "hard" bound and the weighted sum is
+//! mapped onto the 1–5 scale packs use for hand-assigned difficulties.
+
+use super::language::LanguageSpec;
+use super::syntax::{self, TokenType};
+
+/// Coarse difficulty bands, used to filter which samples come up.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Level {
+    Easy,
+    Medium,
+    Hard,
+}
+
+pub const LEVELS: [Level; 3] = [Level::Easy, Level::Medium, Level::Hard];
+
+impl Level {
+    /// The band of a 1–5 difficulty.
+    pub fn of(difficulty: u8) -> Level {
+        match difficulty {
+            0..=2 => Level::Easy,
+            3 => Level::Medium,
+            _ => Level::Hard,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Level::Easy => "easy",
+            Level::Medium => "medium",
+            Level::Hard => "hard",
+        }
+    }
+}
+
+/// `(weight, easy, hard)` for each measure, in the order of [`measures`].
+const SCALES: [(f32, f32, f32); 5] = [
+    (0.30, 60.0, 700.0), // characters
+    (0.20, 0.18, 0.38),  // symbols per non-blank character
+    (0.20, 1.0, 5.0),    // deepest bracket nesting
+    (0.15, 0.10, 0.32),  // shifted characters per non-blank character
+    (0.15, 3.5, 6.5),    // average identifier length
+];
+
+/// Characters typed with Shift on a US layout, besides capital letters.
+const SHIFTED: &str = "~!@#$%^&*()_+{}|:\"<>?";
+
+/// Below this many characters the density measures count only in proportion,
+/// so a one-liner full of symbols is not rated like a page of them.
+const SHORT: f32 = 200.0;
+
+/// Difficulty of `code` from 1 (easiest) to 5.
+pub fn score(code: &str, spec: &LanguageSpec) -> u8 {
+    let measures = measures(code, spec);
+    let scaled: Vec<f32> = measures
+        .iter()
+        .zip(SCALES)
+        .map(|(&value, (weight, easy, hard))| {
+            weight * ((value - easy) / (hard - easy)).clamp(0.0, 1.0)
+        })
+        .collect();
+    let short = (measures[0] / SHORT).min(1.0);
+    let total = scaled[0] + short * scaled[1..].iter().sum::<f32>();
+    1 + (total * 5.0).min(4.0) as u8
+}
+
+/// The raw measures of `code`, see [`SCALES`].
+fn measures(code: &str, spec: &LanguageSpec) -> [f32; 5] {
+    let tokens = syntax::highlight(code, spec);
+    let chars: Vec<char> = code.chars().collect();
+
+    let mut non_blank = 0;
+    let mut symbols = 0;
+    let mut shifted = 0;
+    let (mut depth, mut max_depth) = (0i32, 0i32);
+    let (mut identifiers, mut identifier_chars) = (0, 0);
+    let mut in_identifier = false;
+    for (&c, &token) in chars.iter().zip(&tokens) {
+        if c.is_whitespace() {
+            in_identifier = false;
+            continue;
+        }
+        non_blank += 1;
+        if c.is_ascii_punctuation() {
+            symbols += 1;
+        }
+        if c.is_ascii_uppercase() || SHIFTED.contains(c) {
+            shifted += 1;
+        }
+
+        let literal = matches!(token, TokenType::String | TokenType::Comment);
+        if !literal {
+            match c {
+                '(' | '[' | '{' => {
+                    depth += 1;
+                    max_depth = max_depth.max(depth);
+                }
+                ')' | ']' | '}' => depth -= 1,
+                _ => {}
+            }
+        }
+
+        let word = c.is_alphanumeric() || c == '_';
+        let named = !literal && !matches!(token, TokenType::Keyword | TokenType::Number);
+        if word && named {
+            if !in_identifier {
+                identifiers += 1;
+            }
+            identifier_chars += 1;
+        }
+        in_identifier = word && named;
+    }
+
+    let per_char = |n: usize| n as f32 / non_blank.max(1) as f32;
+    [
+        chars.len() as f32,
+        per_char(symbols),
+        max_depth as f32,
+        per_char(shifted),
+        identifier_chars as f32 / identifiers.max(1) as f32,
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::language::Language;
+
+    #[test]
+    fn longer_denser_code_scores_harder() {
+        let spec = Language::Rust.spec();
+        assert_eq!(score("let x = 1;", spec), 1);
+        let nested = "fn shortest_paths(graph: &HashMap<NodeId, Vec<(NodeId, u64)>>) -> BTreeMap<NodeId, u64> {\n    let mut distances: BTreeMap<NodeId, u64> = BTreeMap::new();\n    let mut frontier = BinaryHeap::from([Reverse((0u64, START_NODE))]);\n    while let Some(Reverse((cost, node))) = frontier.pop() {\n        if distances.contains_key(&node) { continue; }\n        distances.insert(node, cost);\n        for &(next, weight) in graph.get(&node).map_or(&[][..], |v| &v[..]) {\n            frontier.push(Reverse((cost + weight, next)));\n        }\n    }\n    distances\n}";
+        assert!(score(nested, spec) >= 4);
+    }
+
+    #[test]
+    fn strings_and_comments_do_not_count_as_nesting() {
+        let spec = Language::Rust.spec();
+        let [.., depth, _, _] = measures("let s = \"((([[[\"; // {{{", spec);
+        assert_eq!(depth, 0.0);
+        let [.., identifier_length] = measures("let total_count = 1;", spec);
+        assert_eq!(identifier_length, 11.0);
+    }
+
+    #[test]
+    fn levels_band_the_scale() {
+        assert_eq!(
+            (1..=5).map(Level::of).collect::<Vec<_>>(),
+            [
+                Level::Easy,
+                Level::Easy,
+                Level::Medium,
+                Level::Hard,
+                Level::Hard
+            ]
+        );
+    }
+}
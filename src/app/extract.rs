@@ -0,0 +1,817 @@
+//! Samples cut out of a real codebase: its functions (`--repo <path>`) or the
+//! code recently added to it (`--git-history <path>`).
+//!
+//! Files are listed with `git ls-files`, so `.gitignore` is respected; outside a
+//! git checkout the tree is walked instead, skipping hidden entries and the usual
+//! build and dependency directories. Functions are found per language: by the
+//! keyword that introduces them (`fn`, `func`, `def` …) or, for C-style
+//! languages and JavaScript/TypeScript methods and arrow functions, by the
+//! shape of a definition; their extent by brace balancing, or by indentation
+//! for Python.
+//!
+//! Recent changes come from `git log -p -U0`: each run of added lines in a hunk
+//! is a sample when it is function-sized; longer additions (new files, say) are
+//! searched for functions instead.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use super::language::{Language, LanguageSpec};
+use super::syntax::{self, TokenType};
+
+/// Functions shorter or longer than this (in lines) are not worth a sample.
+pub const MIN_LINES: usize = 3;
+pub const MAX_LINES: usize = 40;
+
+/// How many lines a signature may span before its opening brace.
+const MAX_SIGNATURE_LINES: usize = 6;
+
+/// Directories skipped when walking a tree that is not a git checkout.
+const SKIPPED_DIRS: &[&str] = &[
+    "target",
+    "node_modules",
+    "vendor",
+    "build",
+    "dist",
+    "out",
+    "__pycache__",
+];
+
+/// Words that start a statement rather than a function definition.
+const CONTROL_WORDS: &[&str] = &[
+    "if",
+    "else",
+    "for",
+    "while",
+    "do",
+    "switch",
+    "case",
+    "catch",
+    "try",
+    "return",
+    "match",
+    "when",
+    "guard",
+    "with",
+    "loop",
+    "synchronized",
+    "foreach",
+    "new",
+    "throw",
+    "sizeof",
+];
+
+/// Words that may precede a JavaScript/TypeScript method name.
+const JS_METHOD_MODIFIERS: &[&str] = &[
+    "async",
+    "static",
+    "get",
+    "set",
+    "public",
+    "private",
+    "protected",
+    "readonly",
+    "override",
+    "abstract",
+    "*",
+];
+
+/// A function cut out of a source file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Function {
+    /// 1-based line of the first line of the function (including attributes).
+    pub line: usize,
+    /// The function's first line (after any attributes), without the opening brace.
+    pub signature: String,
+    /// The function's code, dedented.
+    pub code: String,
+}
+
+/// How many commits of history `recent_changes` looks through.
+const MAX_COMMITS: usize = 200;
+
+/// Code added by a commit.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Change {
+    pub language: Language,
+    /// Abbreviated commit hash.
+    pub commit: String,
+    pub subject: String,
+    pub path: String,
+    /// 1-based line of the code in the file after the commit.
+    pub line: usize,
+    /// The added code, dedented.
+    pub code: String,
+}
+
+/// Code added in the recent history of the repository at `root`, newest first,
+/// optionally limited to commits whose author matches `author` and made since
+/// `since` (anything `git log --since` accepts: `2024-05-01`, `2.weeks` …).
+pub fn recent_changes(
+    root: &Path,
+    author: Option<&str>,
+    since: Option<&str>,
+) -> io::Result<Vec<Change>> {
+    let mut git = Command::new("git");
+    git.arg("-C").arg(root).args([
+        "log",
+        "--no-merges",
+        "--no-color",
+        "--no-ext-diff",
+        "-p",
+        "-U0",
+        "--format=%x1e%h%x1f%s",
+    ]);
+    git.arg(format!("--max-count={MAX_COMMITS}"));
+    if let Some(author) = author {
+        git.arg(format!("--author={author}"));
+    }
+    if let Some(since) = since {
+        git.arg(format!("--since={since}"));
+    }
+    let output = git.output()?;
+    if !output.status.success() {
+        return Err(io::Error::other(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+    Ok(parse_log(&String::from_utf8_lossy(&output.stdout)))
+}
+
+/// Parse the output of `git log -p -U0 --format=%x1e%h%x1f%s`.
+fn parse_log(log: &str) -> Vec<Change> {
+    let mut changes: Vec<Change> = Vec::new();
+    for commit in log.split('\x1e').filter(|c| !c.is_empty()) {
+        let (header, diff) = commit.split_once('\n').unwrap_or((commit, ""));
+        let (hash, subject) = header.split_once('\x1f').unwrap_or((header, ""));
+
+        let mut file: Option<(String, Language)> = None;
+        let mut line = 0;
+        let mut added: Vec<&str> = Vec::new();
+        let mut flush = |added: &mut Vec<&str>, file: &Option<(String, Language)>, line: usize| {
+            if let Some((path, language)) = file {
+                for (offset, code) in added_code(added, *language) {
+                    if !changes.iter().any(|c| c.code == code) {
+                        changes.push(Change {
+                            language: *language,
+                            commit: hash.to_string(),
+                            subject: subject.to_string(),
+                            path: path.clone(),
+                            line: line + offset,
+                            code,
+                        });
+                    }
+                }
+            }
+            added.clear();
+        };
+
+        // Lines of the current hunk still to come, (removed, added), counted from
+        // its header: inside it, `+++ x` is an added line `++ x`, not a file header
+        let mut hunk_left: (usize, usize) = (0, 0);
+        for text in diff.lines() {
+            if hunk_left != (0, 0) {
+                if let Some(code) = text.strip_prefix('+') {
+                    added.push(code);
+                    hunk_left.1 = hunk_left.1.saturating_sub(1);
+                } else if text.starts_with('-') {
+                    hunk_left.0 = hunk_left.0.saturating_sub(1);
+                } else if !text.starts_with('\\') {
+                    // Context, though `-U0` leaves none
+                    hunk_left = (hunk_left.0.saturating_sub(1), hunk_left.1.saturating_sub(1));
+                }
+                continue;
+            }
+            if let Some(path) = text.strip_prefix("+++ ") {
+                flush(&mut added, &file, line);
+                file = path.strip_prefix("b/").and_then(|path| {
+                    let ext = Path::new(path).extension()?.to_str()?;
+                    Some((path.to_string(), Language::from_extension(ext)?))
+                });
+            } else if let Some(hunk) = text.strip_prefix("@@ ") {
+                flush(&mut added, &file, line);
+                // `@@ -a,b +c,d @@`: b lines removed at a, d added from line c.
+                let mut ranges = hunk.split_whitespace();
+                let removed = ranges.next().and_then(|r| hunk_range(r.strip_prefix('-')?));
+                let added_range = ranges.next().and_then(|r| hunk_range(r.strip_prefix('+')?));
+                line = added_range.map_or(1, |(start, _)| start);
+                hunk_left = (
+                    removed.map_or(0, |(_, len)| len),
+                    added_range.map_or(0, |(_, len)| len),
+                );
+            }
+        }
+        flush(&mut added, &file, line);
+    }
+    changes
+}
+
+/// `start,len` (or just `start`, for one line) of a hunk header.
+fn hunk_range(range: &str) -> Option<(usize, usize)> {
+    let (start, len) = range.split_once(',').unwrap_or((range, "1"));
+    Some((start.parse().ok()?, len.parse().ok()?))
+}
+
+/// The samples in a run of added lines: all of it when it is function-sized,
+/// otherwise the functions inside it, as `(line offset, dedented code)`.
+fn added_code(added: &[&str], language: Language) -> Vec<(usize, String)> {
+    let first = added.iter().position(|l| !l.trim().is_empty());
+    let last = added.iter().rposition(|l| !l.trim().is_empty());
+    let (Some(first), Some(last)) = (first, last) else {
+        return Vec::new();
+    };
+    let lines = &added[first..=last];
+    if (MIN_LINES..=MAX_LINES).contains(&lines.len()) {
+        vec![(first, dedent(lines))]
+    } else if lines.len() > MAX_LINES {
+        functions(&lines.join("\n"), language)
+            .into_iter()
+            .map(|f| (first + f.line - 1, f.code))
+            .collect()
+    } else {
+        Vec::new()
+    }
+}
+
+/// Source files of `root`, relative to it.
+pub fn repo_files(root: &Path) -> io::Result<Vec<PathBuf>> {
+    let git = Command::new("git")
+        .arg("-C")
+        .arg(root)
+        .args([
+            "ls-files",
+            "-z",
+            "--cached",
+            "--others",
+            "--exclude-standard",
+        ])
+        .output();
+    if let Ok(output) = git {
+        if output.status.success() {
+            return Ok(output
+                .stdout
+                .split(|&b| b == 0)
+                .filter(|path| !path.is_empty())
+                .map(|path| PathBuf::from(String::from_utf8_lossy(path).into_owned()))
+                .collect());
+        }
+    }
+    let mut files = Vec::new();
+    walk(root, Path::new(""), &mut files)?;
+    files.sort();
+    Ok(files)
+}
+
+fn walk(root: &Path, relative: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(root.join(relative))? {
+        let entry = entry?;
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        if name.starts_with('.') {
+            continue;
+        }
+        let path = relative.join(&*name);
+        if entry.file_type()?.is_dir() {
+            if !SKIPPED_DIRS.contains(&&*name) {
+                walk(root, &path, files)?;
+            }
+        } else {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Every function of `code` between [`MIN_LINES`] and [`MAX_LINES`] long.
+/// Nested functions are only returned when their parent is too long.
+pub fn functions(code: &str, language: Language) -> Vec<Function> {
+    let spec = language.spec();
+    // Token offsets below assume one char per line break.
+    let code = &code.replace("\r\n", "\n");
+    let lines: Vec<&str> = code.lines().collect();
+    let mut found = Vec::new();
+
+    // Per-char token kinds, so braces in strings and comments are not counted.
+    let tokens = syntax::highlight(code, spec);
+    let mut line_starts = Vec::with_capacity(lines.len());
+    let mut offset = 0;
+    for line in &lines {
+        line_starts.push(offset);
+        offset += line.chars().count() + 1;
+    }
+
+    let js = matches!(language, Language::JavaScript | Language::TypeScript);
+    let mut i = 0;
+    while i < lines.len() {
+        let defines = is_definition(lines[i], spec) || (js && is_js_definition(lines[i]));
+        let end = if !defines {
+            None
+        } else if language == Language::Python {
+            indented_block_end(&lines, &line_starts, &tokens, i)
+        } else {
+            braced_block_end(&lines, &line_starts, &tokens, i)
+        };
+        let Some(end) = end else {
+            i += 1;
+            continue;
+        };
+
+        let start = attributes_start(&lines, i);
+        let len = end - start + 1;
+        if !(MIN_LINES..=MAX_LINES).contains(&len) {
+            i += 1;
+            continue;
+        }
+        found.push(Function {
+            line: start + 1,
+            signature: lines[i].trim().trim_end_matches('{').trim_end().to_string(),
+            code: dedent(&lines[start..=end]),
+        });
+        i = end + 1;
+    }
+    found
+}
+
+/// Whether `line` looks like the start of a function definition.
+fn is_definition(line: &str, spec: &LanguageSpec) -> bool {
+    let text = line.trim();
+    let Some(paren) = text.find('(') else {
+        return false;
+    };
+    let head = &text[..paren];
+    let words: Vec<&str> = head.split_whitespace().collect();
+    if !spec.function_keywords.is_empty() {
+        return words.iter().any(|w| spec.function_keywords.contains(w));
+    }
+    // C-style: `[modifiers] type name(args)`, not a call, statement or assignment.
+    let starts_like_a_type = text
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_alphabetic() || c == '_');
+    starts_like_a_type
+        && words.len() >= 2
+        && !words.iter().any(|w| CONTROL_WORDS.contains(w))
+        && !head.contains('=')
+        && !text.ends_with(';')
+}
+
+/// Whether `line` starts a JavaScript/TypeScript function that has no
+/// `function` keyword: an arrow function bound to a name
+/// (`const add = (a, b) => {`) or a method (`async load(id) {`).
+fn is_js_definition(line: &str) -> bool {
+    let text = line.trim();
+    if !text.ends_with('{') {
+        return false;
+    }
+    if let Some((head, value)) = text.split_once(" = ") {
+        if head
+            .split_whitespace()
+            .any(|w| matches!(w, "const" | "let" | "var"))
+        {
+            let value = value.trim_start();
+            let value = value.strip_prefix("async ").unwrap_or(value).trim_start();
+            return after_params(value)
+                .is_some_and(|rest| rest.starts_with("=>") || rest.starts_with(':'));
+        }
+    }
+    let Some(paren) = text.find('(') else {
+        return false;
+    };
+    let mut words: Vec<&str> = text[..paren].split_whitespace().collect();
+    let Some(name) = words.pop() else {
+        return false;
+    };
+    // Generic methods: `map<T>(…)`.
+    let name = name.split('<').next().unwrap_or(name);
+    let name = name.trim_start_matches(['#', '*']).trim_end_matches('?');
+    name.chars()
+        .all(|c| c.is_alphanumeric() || c == '_' || c == '$')
+        && name.chars().next().is_some_and(|c| !c.is_ascii_digit())
+        && !CONTROL_WORDS.contains(&name)
+        && words.iter().all(|w| JS_METHOD_MODIFIERS.contains(w))
+        && after_params(&text[paren..]).is_some_and(|rest| rest == "{" || rest.starts_with(':'))
+}
+
+/// What follows the parameters at the start of `text`: a parenthesised list,
+/// or the single bare parameter of an arrow function (`x => …`).
+fn after_params(text: &str) -> Option<&str> {
+    if !text.starts_with('(') {
+        let end = text.find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '$'))?;
+        return (end > 0).then(|| text[end..].trim_start());
+    }
+    let mut depth = 0;
+    for (idx, ch) in text.char_indices() {
+        match ch {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(text[idx + 1..].trim_start());
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Index of the line holding the brace that closes the block opened after the
+/// signature starting on line `start`, if the signature is followed by a body.
+fn braced_block_end(
+    lines: &[&str],
+    line_starts: &[usize],
+    tokens: &[TokenType],
+    start: usize,
+) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut opened = false;
+    for (idx, line) in lines.iter().enumerate().skip(start) {
+        if !opened && idx >= start + MAX_SIGNATURE_LINES {
+            return None;
+        }
+        for (col, ch) in line.chars().enumerate() {
+            let kind = tokens.get(line_starts[idx] + col).copied();
+            if matches!(kind, Some(TokenType::String | TokenType::Comment)) {
+                continue;
+            }
+            match ch {
+                '{' => {
+                    depth += 1;
+                    opened = true;
+                }
+                '}' if opened => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Some(idx);
+                    }
+                }
+                // A declaration (prototype, trait method without a body).
+                ';' if !opened => return None,
+                _ => {}
+            }
+        }
+    }
+    None
+}
+
+/// Index of the last line of the indented block whose header starts on line
+/// `start`.
+fn indented_block_end(
+    lines: &[&str],
+    line_starts: &[usize],
+    tokens: &[TokenType],
+    start: usize,
+) -> Option<usize> {
+    let indent = indentation(lines[start]);
+    let header_end = header_end(lines, line_starts, tokens, start)?;
+    let mut end = None;
+    for (idx, line) in lines.iter().enumerate().skip(header_end + 1) {
+        if line.trim().is_empty() {
+            continue;
+        }
+        if indentation(line) <= indent {
+            break;
+        }
+        end = Some(idx);
+    }
+    end
+}
+
+/// Index of the line that ends the header starting on line `start`: the first
+/// where brackets are balanced again and the code ends in `:`. A signature
+/// wrapped Black-style closes with `):` at the `def`'s own indentation.
+fn header_end(
+    lines: &[&str],
+    line_starts: &[usize],
+    tokens: &[TokenType],
+    start: usize,
+) -> Option<usize> {
+    let mut depth = 0i32;
+    for (idx, line) in lines.iter().enumerate().skip(start) {
+        let mut last = None;
+        for (col, ch) in line.chars().enumerate() {
+            let kind = tokens.get(line_starts[idx] + col).copied();
+            if ch.is_whitespace() || matches!(kind, Some(TokenType::String | TokenType::Comment)) {
+                continue;
+            }
+            match ch {
+                '(' | '[' | '{' => depth += 1,
+                ')' | ']' | '}' => depth -= 1,
+                _ => {}
+            }
+            last = Some(ch);
+        }
+        if depth <= 0 {
+            // A one-line function (`def f(): pass`) has no block to measure.
+            return (last == Some(':')).then_some(idx);
+        }
+    }
+    None
+}
+
+/// The first line of the attributes/decorators directly above line `start`.
+fn attributes_start(lines: &[&str], start: usize) -> usize {
+    let indent = indentation(lines[start]);
+    let mut first = start;
+    while first > 0 {
+        let above = lines[first - 1];
+        let text = above.trim_start();
+        if indentation(above) == indent && (text.starts_with("#[") || text.starts_with('@')) {
+            first -= 1;
+        } else {
+            break;
+        }
+    }
+    first
+}
+
+fn indentation(line: &str) -> usize {
+    line.len() - line.trim_start().len()
+}
+
+/// Join `lines`, removing the leading whitespace they all share.
+fn dedent(lines: &[&str]) -> String {
+    let mut non_blank = lines.iter().filter(|l| !l.trim().is_empty());
+    let first = non_blank.next().map_or("", |l| &l[..indentation(l)]);
+    let common = non_blank.fold(first, |common, line| {
+        // Compared by char, so a multibyte space is never cut in half
+        let shared: usize = common
+            .chars()
+            .zip(line.chars())
+            .take_while(|(a, b)| a == b)
+            .map(|(c, _)| c.len_utf8())
+            .sum();
+        &common[..shared]
+    });
+    lines
+        .iter()
+        .map(|l| l.strip_prefix(common).unwrap_or("").trim_end())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::test_util::TempDir;
+
+    #[test]
+    fn rust_functions_are_cut_by_brace_balancing() {
+        let code = "\
+use std::fmt;
+
+impl Point {
+    #[inline]
+    pub fn norm(&self) -> f64 {
+        let s = \"}\"; // a brace in a string: }
+        (self.x * self.x + self.y * self.y).sqrt()
+    }
+
+    fn tiny(&self) {}
+}
+
+trait Shape {
+    fn area(&self) -> f64;
+}
+";
+        let found = functions(code, Language::Rust);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].line, 4);
+        assert_eq!(found[0].signature, "pub fn norm(&self) -> f64");
+        assert_eq!(
+            found[0].code,
+            "#[inline]\npub fn norm(&self) -> f64 {\n    let s = \"}\"; // a brace in a string: }\n    (self.x * self.x + self.y * self.y).sqrt()\n}"
+        );
+    }
+
+    #[test]
+    fn c_style_definitions_are_recognised_by_shape() {
+        let code = "\
+int helper(int x);
+
+static int square(int x)
+{
+    if (x < 0) {
+        x = -x;
+    }
+    return x * x;
+}
+";
+        let found = functions(code, Language::C);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].signature, "static int square(int x)");
+        assert_eq!(found[0].code.lines().count(), 7);
+    }
+
+    #[test]
+    fn js_methods_and_arrow_functions_are_found() {
+        let code = "\
+class Cart {
+  constructor(items) {
+    this.items = items;
+    this.open = true;
+  }
+
+  async total(tax = 0) {
+    const sum = this.items.reduce((a, b) => a + b, 0);
+    return sum * (1 + tax);
+  }
+}
+
+export const double = (xs) => {
+  const out = xs.map((x) => x * 2);
+  return out;
+};
+
+const square = x => x * x;
+
+describe('cart', () => {
+  it('adds', () => {
+    expect(1).toBe(1);
+  });
+});
+
+if (ready) {
+  start();
+  stop();
+}
+";
+        let signatures: Vec<String> = functions(code, Language::JavaScript)
+            .into_iter()
+            .map(|f| f.signature)
+            .collect();
+        assert_eq!(
+            signatures,
+            [
+                "constructor(items)",
+                "async total(tax = 0)",
+                "export const double = (xs) =>",
+            ]
+        );
+    }
+
+    #[test]
+    fn ts_methods_with_types_are_found() {
+        let code = "\
+export class Store {
+  private async load<T>(id: string): Promise<T> {
+    const res = await fetch(`/items/${id}`);
+    return res.json();
+  }
+}
+
+const handler: Handler = async (req: Request): Promise<Response> => {
+  const body = await req.text();
+  return new Response(body);
+};
+";
+        let signatures: Vec<String> = functions(code, Language::TypeScript)
+            .into_iter()
+            .map(|f| f.signature)
+            .collect();
+        assert_eq!(
+            signatures,
+            [
+                "private async load<T>(id: string): Promise<T>",
+                "const handler: Handler = async (req: Request): Promise<Response> =>",
+            ]
+        );
+    }
+
+    #[test]
+    fn python_functions_end_where_the_indentation_does() {
+        let code = "\
+class Greeter:
+    @staticmethod
+    def greet(name):
+        if name:
+            return f'hi {name}'
+
+        return 'hi'
+
+    def other(self):
+        pass
+";
+        let found = functions(code, Language::Python);
+        assert_eq!(found.len(), 1);
+        assert_eq!(
+            found[0].code,
+            "@staticmethod\ndef greet(name):\n    if name:\n        return f'hi {name}'\n\n    return 'hi'"
+        );
+    }
+
+    #[test]
+    fn wrapped_python_signatures_keep_their_body() {
+        let code = "\
+def total(
+    items,
+    tax=0.2,  # a rate, not a percentage (
+) -> float:
+    subtotal = sum(items)
+    return subtotal * (1 + tax)
+
+print(total([1, 2]))
+";
+        let found = functions(code, Language::Python);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].code.lines().count(), 6);
+        assert!(found[0].code.ends_with("    return subtotal * (1 + tax)"));
+    }
+
+    #[test]
+    fn dedent_strips_only_the_shared_whitespace() {
+        assert_eq!(dedent(&["    a", "", "      b  "]), "a\n\n  b");
+        // A full-width space is not the two spaces the other lines start with
+        assert_eq!(
+            dedent(&["  fn f() {", "\u{3000}x", "  }"]),
+            "  fn f() {\n\u{3000}x\n  }"
+        );
+    }
+
+    #[test]
+    fn added_hunks_become_changes() {
+        let log = "\x1eabc1234\x1fAdd a parser\n\n\
+diff --git a/src/parse.rs b/src/parse.rs
+--- a/src/parse.rs
++++ b/src/parse.rs
+@@ -10,0 +11,4 @@ impl Parser {
++    fn peek(&self) -> Option<char> {
++        self.chars.get(self.pos).copied()
++    }
++
+@@ -30,1 +35,1 @@ impl Parser {
+-        old()
++        new()
+diff --git a/README.md b/README.md
+--- a/README.md
++++ b/README.md
+@@ -1,0 +2,3 @@
++# one
++# two
++# three
+\x1edef5678\x1fRemove a file\n\n\
+diff --git a/gone.py b/gone.py
+--- a/gone.py
++++ /dev/null
+@@ -1,3 +0,0 @@
+-a
+-b
+-c
+";
+        let changes = parse_log(log);
+        assert_eq!(
+            changes,
+            vec![Change {
+                language: Language::Rust,
+                commit: "abc1234".to_string(),
+                subject: "Add a parser".to_string(),
+                path: "src/parse.rs".to_string(),
+                line: 11,
+                code: "fn peek(&self) -> Option<char> {\n    self.chars.get(self.pos).copied()\n}"
+                    .to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn added_lines_that_look_like_file_headers_stay_in_their_file() {
+        let log = "\x1eabc1234\x1fIncrement\n\n\
+diff --git a/inc.c b/inc.c
+--- a/inc.c
++++ b/inc.c
+@@ -0,0 +1,4 @@
++int next(int x) {
++++ x;
++return x;
++}
+";
+        let changes = parse_log(log);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].path, "inc.c");
+        assert_eq!(changes[0].code, "int next(int x) {\n++ x;\nreturn x;\n}");
+    }
+
+    #[test]
+    fn files_are_listed_without_hidden_or_build_directories() {
+        let dir = TempDir::new("repo");
+        for sub in ["src", "target", ".hidden"] {
+            fs::create_dir_all(dir.join(sub)).unwrap();
+            fs::write(dir.join(sub).join("lib.rs"), "").unwrap();
+        }
+        // Not a git checkout (unless the temp dir is inside one), so the tree is walked.
+        let in_git = Command::new("git")
+            .arg("-C")
+            .arg(&*dir)
+            .args(["rev-parse", "--git-dir"])
+            .output()
+            .is_ok_and(|o| o.status.success());
+        if !in_git {
+            assert_eq!(
+                repo_files(&dir).unwrap(),
+                vec![PathBuf::from("src").join("lib.rs")]
+            );
+        }
+    }
+}
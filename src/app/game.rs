@@ -4,19 +4,31 @@ use ratatui::Terminal;
 use std::io;
 use std::time::{Duration, Instant};
 
+use super::history;
+use super::mode::Mode;
+use super::replay::{Player, Recording, SPEEDS};
 use super::state::Screen;
 use super::{ui, GameState};
 
 pub struct Game {
     game_state: GameState,
     terminal: Terminal<CrosstermBackend<io::Stdout>>,
+    /// Keys of the run in progress, with when they were pressed.
+    keys: Vec<(Instant, KeyCode)>,
+    /// The last finished run, replayed with `r` on the completion screen.
+    last_recording: Option<Recording>,
+    /// Set by `r`; the replay itself runs from the main loop.
+    replay_requested: bool,
 }
 
 impl Game {
-    pub fn new(terminal: Terminal<CrosstermBackend<io::Stdout>>) -> Self {
+    pub fn new(terminal: Terminal<CrosstermBackend<io::Stdout>>, game_state: GameState) -> Self {
         Game {
-            game_state: GameState::new(),
+            game_state,
             terminal,
+            keys: Vec::new(),
+            last_recording: None,
+            replay_requested: false,
         }
     }
 
@@ -39,12 +51,90 @@ impl Game {
                 }
             }
 
+            if std::mem::take(&mut self.replay_requested) {
+                if let Some(recording) = self.last_recording.clone() {
+                    self.replay(&recording)?;
+                }
+            }
+
             if last_tick.elapsed() >= tick_rate {
+                self.game_state.tick();
                 last_tick = Instant::now();
             }
         }
     }
 
+    /// Play `recording` back on the typing screen: its keys are fed through
+    /// `handle_input` at their original offsets against a virtual clock, so the
+    /// timer, stats and charts come out as they did in the real run. Space pauses,
+    /// 1/2/4 set the speed and Esc/q stops; once the run completes any key returns.
+    /// Replayed runs are not added to the history.
+    pub fn replay(&mut self, recording: &Recording) -> io::Result<()> {
+        let tick_rate = Duration::from_millis(50);
+        self.game_state.load_code(
+            recording.language,
+            &recording.code,
+            &recording.expected_output,
+        );
+        let mut player = Player::new(recording);
+        let stop_on_error =
+            std::mem::replace(&mut self.game_state.stop_on_error, recording.stop_on_error);
+        let start = Instant::now();
+        let mut last_tick = start;
+        self.game_state.replay = Some(player.status);
+        self.game_state.clock = Some(start);
+
+        loop {
+            self.terminal.draw(|f| ui::draw(f, &self.game_state))?;
+
+            let timeout = tick_rate
+                .checked_sub(last_tick.elapsed())
+                .unwrap_or_else(|| Duration::from_secs(0));
+
+            if crossterm::event::poll(timeout)? {
+                if let Event::Key(key) = event::read()? {
+                    if self.game_state.game_over {
+                        break;
+                    }
+                    match key.code {
+                        KeyCode::Char(' ') => player.toggle_pause(),
+                        KeyCode::Char(c) if c.is_ascii_digit() => {
+                            if let Some(&speed) =
+                                SPEEDS.iter().find(|&&s| char::from_digit(s, 10) == Some(c))
+                            {
+                                player.set_speed(speed);
+                            }
+                        }
+                        KeyCode::Esc | KeyCode::Char('q') => break,
+                        _ => {}
+                    }
+                }
+            }
+
+            let now = Instant::now();
+            for (at, key) in player.advance(now - last_tick) {
+                self.game_state.clock = Some(start + at);
+                self.game_state.tick();
+                self.handle_input(key);
+            }
+            last_tick = now;
+            if !self.game_state.game_over {
+                self.game_state.clock = Some(start + player.position());
+                self.game_state.tick();
+            }
+            self.game_state.replay = Some(player.status);
+        }
+
+        self.game_state.replay = None;
+        self.game_state.clock = None;
+        self.game_state.stop_on_error = stop_on_error;
+        if !self.game_state.game_over {
+            // Stopped halfway: leave a fresh attempt rather than a half-typed replay
+            self.game_state.restart_current();
+        }
+        Ok(())
+    }
+
     /// Returns `false` to quit the game.
     fn handle_input(&mut self, key: KeyCode) -> bool {
         // Quit confirmation intercepts everything else
@@ -62,6 +152,8 @@ impl Game {
         match self.game_state.screen {
             Screen::Menu => self.handle_menu_input(key),
             Screen::Typing => self.handle_typing_input(key),
+            Screen::Stats | Screen::Heatmap => self.handle_stats_input(key),
+            Screen::Browser => self.handle_browser_input(key),
         }
     }
 
@@ -70,19 +162,71 @@ impl Game {
             KeyCode::Up | KeyCode::Char('k') => self.game_state.menu_up(),
             KeyCode::Down | KeyCode::Char('j') => self.game_state.menu_down(),
             KeyCode::Enter => self.game_state.select_menu_language(),
+            KeyCode::Char('s') => self.game_state.open_stats(),
+            KeyCode::Char('h') => self.game_state.open_heatmap(),
+            KeyCode::Char('b') => self.game_state.open_browser(),
+            KeyCode::Char('d') => self.game_state.cycle_difficulty(),
+            KeyCode::Char('m') => self.game_state.cycle_mode(),
+            KeyCode::Char('e') => self.game_state.stop_on_error ^= true,
             KeyCode::Esc | KeyCode::Char('q') => self.game_state.confirm_quit = true,
             _ => {}
         }
         true
     }
 
+    fn handle_stats_input(&mut self, key: KeyCode) -> bool {
+        match key {
+            KeyCode::Left | KeyCode::Char('h') => self.game_state.stats_prev(),
+            KeyCode::Right | KeyCode::Char('l') => self.game_state.stats_next(),
+            KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => {
+                self.game_state.screen = Screen::Menu
+            }
+            _ => {}
+        }
+        true
+    }
+
+    /// Letters go to the search query, so only non-character keys navigate.
+    fn handle_browser_input(&mut self, key: KeyCode) -> bool {
+        match key {
+            KeyCode::Up => self.game_state.browser_up(),
+            KeyCode::Down => self.game_state.browser_down(),
+            KeyCode::Enter => self.game_state.select_browser_sample(),
+            KeyCode::Tab => self.game_state.browser_toggle_sort(),
+            KeyCode::Char(c) => self.game_state.browser_type(c),
+            KeyCode::Backspace => self.game_state.browser_backspace(),
+            KeyCode::Esc if !self.game_state.browser_query.is_empty() => {
+                self.game_state.browser_query.clear();
+                self.game_state.browser_index = 0;
+            }
+            KeyCode::Esc => self.game_state.screen = Screen::Menu,
+            _ => {}
+        }
+        true
+    }
+
     fn handle_typing_input(&mut self, key: KeyCode) -> bool {
+        let was_over = self.game_state.game_over;
+        if self.game_state.first_input_time.is_none() {
+            self.keys.clear();
+            if self.game_state.replay.is_none() {
+                // A new run: `r` replays the run on screen, not an earlier one
+                self.last_recording = None;
+            }
+        }
+
         match key {
+            KeyCode::Char('r') if was_over && self.game_state.replay.is_none() => {
+                self.replay_requested = true;
+            }
+            KeyCode::Char('n') if was_over && self.game_state.replay.is_none() => {
+                self.game_state.next_part();
+            }
             KeyCode::Char(c) => self.game_state.handle_input(c),
             KeyCode::Tab => self.game_state.handle_input('\t'),
             KeyCode::Backspace => self.game_state.handle_backspace(),
             KeyCode::Enter => {
-                if self.game_state.game_over {
+                if was_over {
                     self.game_state.reset();
                 } else {
                     self.game_state.handle_input('\n');
@@ -91,15 +235,20 @@ impl Game {
             // Switch sample — only before typing starts (handled in state)
             KeyCode::Left | KeyCode::Right => self.game_state.random_sample(),
             // Back to the language menu — only when not mid-typing
-            KeyCode::Up | KeyCode::Down => {
-                if self.game_state.first_input_time.is_none() || self.game_state.game_over {
-                    self.game_state.open_menu();
-                }
+            KeyCode::Up | KeyCode::Down
+                if self.game_state.first_input_time.is_none() || self.game_state.game_over =>
+            {
+                self.game_state.open_menu();
             }
             KeyCode::Esc => {
                 if self.game_state.first_input_time.is_some() && !self.game_state.game_over {
-                    // Typing in progress - restart current sample
-                    self.game_state.restart_current();
+                    if let Mode::Daily(_) = self.game_state.run_mode() {
+                        // The daily challenge has one attempt: giving up ends it
+                        self.game_state.finish_game();
+                    } else {
+                        // Typing in progress - restart current sample
+                        self.game_state.restart_current();
+                    }
                 } else {
                     // Not started or game over - show quit confirmation
                     self.game_state.confirm_quit = true;
@@ -107,6 +256,48 @@ impl Game {
             }
             _ => {}
         }
+
+        self.record_key(key, was_over);
         true
     }
+
+    /// Add a typing key to the current recording, and save the recording once
+    /// the key finishes the run.
+    fn record_key(&mut self, key: KeyCode, was_over: bool) {
+        let typing = matches!(
+            key,
+            KeyCode::Char(_) | KeyCode::Tab | KeyCode::Backspace | KeyCode::Enter
+        );
+        if was_over
+            || !typing
+            || self.game_state.replay.is_some()
+            || self.game_state.first_input_time.is_none()
+        {
+            return;
+        }
+        self.keys.push((Instant::now(), key));
+
+        // A run over several samples cannot be replayed against one of them
+        if self.game_state.game_over && !self.game_state.run_mode().streams() {
+            let first = self.keys[0].0;
+            let recording = Recording {
+                timestamp: history::unix_now(),
+                language: self.game_state.language,
+                code: self.game_state.current_code.clone(),
+                expected_output: self.game_state.expected_output.clone(),
+                wpm: self.game_state.wpm,
+                accuracy: self.game_state.accuracy,
+                events: self
+                    .keys
+                    .drain(..)
+                    .map(|(at, key)| ((at - first).as_millis() as u64, key))
+                    .collect(),
+                stop_on_error: self.game_state.stop_on_error,
+            };
+            // A replay is a nice-to-have; failing to save one must not end the session
+            let _ = recording.save_to_data_dir();
+            self.game_state.offer_ghost(&recording);
+            self.last_recording = Some(recording);
+        }
+    }
 }
@@ -0,0 +1,405 @@
+//! Persistent run history.
+//!
+//! Every completed run is appended as a single JSON line to
+//! `$XDG_DATA_HOME/code-typing/history.jsonl` (falling back to
+//! `~/.local/share/code-typing`). Records are only ever appended, each with one
+//! `write` followed by an fsync, so a crash can at worst leave a torn final line —
+//! which the loader skips instead of discarding the whole file.
+
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use super::language::Language;
+use super::mode::Mode;
+
+const HISTORY_FILE: &str = "history.jsonl";
+
+/// One finished run, as stored on disk. Fields added after the first release
+/// are `#[serde(default)]` so older history files keep loading.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct RunRecord {
+    /// Unix timestamp (seconds) of when the run was completed.
+    pub timestamp: u64,
+    pub language: Language,
+    /// Stable content hash of the sample code (see [`sample_id`]). Empty for
+    /// timed and sudden-death runs, which span several samples.
+    pub sample_id: String,
+    /// Net WPM: only correctly typed characters count.
+    pub wpm: f32,
+    /// Net accuracy: share of the final input that is correct.
+    pub accuracy: f32,
+    pub duration_secs: f32,
+    /// Every key pressed while typing, including backspaces.
+    pub keystrokes: usize,
+    /// Keystrokes that did not match the expected character, corrected or not.
+    pub errors: usize,
+    /// WPM over every character entered, including mistakes and deleted ones.
+    #[serde(default)]
+    pub raw_wpm: f32,
+    /// Share of all judged keystrokes that were right, even if fixed later.
+    #[serde(default)]
+    pub raw_accuracy: f32,
+    #[serde(default)]
+    pub corrected_errors: usize,
+    #[serde(default)]
+    pub uncorrected_errors: usize,
+    /// `(expected, typed, count)` for every mismatched keystroke.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub confusions: Vec<(char, char, u32)>,
+    /// `(sequence, total_ms, count)` latency of each correctly typed bigram and trigram.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ngrams: Vec<(String, f32, u32)>,
+    /// 100 minus the coefficient of variation of per-second WPM.
+    #[serde(default)]
+    pub consistency: f32,
+    /// Fastest single second, in WPM.
+    #[serde(default)]
+    pub burst_wpm: f32,
+    #[serde(default, skip_serializing_if = "Mode::is_sample")]
+    pub mode: Mode,
+    /// Characters typed before the first mistake, in sudden-death runs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub streak: Option<usize>,
+}
+
+/// The best WPM and best accuracy reached over a set of runs (not necessarily
+/// in the same run).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PersonalBest {
+    pub wpm: f32,
+    pub accuracy: f32,
+}
+
+impl PersonalBest {
+    fn of<'a>(runs: impl Iterator<Item = &'a RunRecord>) -> Option<Self> {
+        runs.fold(None, |best: Option<Self>, run| {
+            Some(match best {
+                Some(b) => PersonalBest {
+                    wpm: b.wpm.max(run.wpm),
+                    accuracy: b.accuracy.max(run.accuracy),
+                },
+                None => PersonalBest {
+                    wpm: run.wpm,
+                    accuracy: run.accuracy,
+                },
+            })
+        })
+    }
+}
+
+/// All recorded runs, plus where (if anywhere) new ones are persisted.
+#[derive(Clone, Debug, Default)]
+pub struct History {
+    /// `None` keeps the history in memory only (tests, or no data directory).
+    path: Option<PathBuf>,
+    pub runs: Vec<RunRecord>,
+}
+
+impl History {
+    /// Load the history from the default data directory. A missing file is an
+    /// empty history; without a resolvable data directory nothing is persisted.
+    pub fn load() -> io::Result<Self> {
+        match data_dir() {
+            Some(dir) => Self::open(dir.join(HISTORY_FILE)),
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// Load the history stored at `path`.
+    pub fn open(path: PathBuf) -> io::Result<Self> {
+        let runs = match fs::read_to_string(&path) {
+            Ok(text) => text
+                .lines()
+                // A torn or hand-edited line must not take the rest of the history with it.
+                .filter_map(|line| serde_json::from_str(line).ok())
+                .collect(),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Vec::new(),
+            Err(e) => return Err(e),
+        };
+        Ok(History {
+            path: Some(path),
+            runs,
+        })
+    }
+
+    /// Record a finished run, appending it to disk when the history is persistent.
+    pub fn append(&mut self, record: RunRecord) -> io::Result<()> {
+        if let Some(path) = &self.path {
+            append_line(path, &serde_json::to_string(&record)?)?;
+        }
+        self.runs.push(record);
+        Ok(())
+    }
+
+    /// Personal best on one sample (identified by [`sample_id`]).
+    pub fn best_for_sample(&self, sample_id: &str) -> Option<PersonalBest> {
+        PersonalBest::of(
+            self.single_sample_runs()
+                .filter(|r| r.sample_id == sample_id),
+        )
+    }
+
+    /// Personal best over every sample of a language.
+    pub fn best_for_language(&self, language: Language) -> Option<PersonalBest> {
+        PersonalBest::of(self.single_sample_runs().filter(|r| r.language == language))
+    }
+
+    /// Runs of one sample from start to end. The WPM and accuracy of a run
+    /// that streams samples cover all of them, so they are no sample's best.
+    fn single_sample_runs(&self) -> impl Iterator<Item = &RunRecord> {
+        self.runs
+            .iter()
+            .filter(|r| matches!(r.mode, Mode::Sample | Mode::Daily(_)))
+    }
+
+    /// The scored run of the daily challenge of `day`, if it was played.
+    pub fn daily_result(&self, day: u64) -> Option<&RunRecord> {
+        self.runs.iter().find(|r| r.mode == Mode::Daily(day))
+    }
+
+    /// Longest sudden-death streak in a language.
+    pub fn best_streak(&self, language: Language) -> Option<usize> {
+        self.runs
+            .iter()
+            .filter(|r| r.language == language)
+            .filter_map(|r| r.streak)
+            .max()
+    }
+}
+
+/// Append `line` to the file at `path` durably, creating parent directories as needed.
+fn append_line(path: &Path, line: &str) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut file = OpenOptions::new()
+        .read(true)
+        .append(true)
+        .create(true)
+        .open(path)?;
+
+    // If a previous write was cut short, terminate the torn line first so this
+    // record does not get glued onto it.
+    let mut buf = String::new();
+    if needs_newline(&mut file)? {
+        buf.push('\n');
+    }
+    buf.push_str(line);
+    buf.push('\n');
+
+    file.write_all(buf.as_bytes())?;
+    file.sync_data()
+}
+
+fn needs_newline(file: &mut File) -> io::Result<bool> {
+    if file.metadata()?.len() == 0 {
+        return Ok(false);
+    }
+    let mut last = [0u8; 1];
+    file.seek(SeekFrom::End(-1))?;
+    file.read_exact(&mut last)?;
+    Ok(last[0] != b'\n')
+}
+
+/// Directory where code-typing keeps its data (`$XDG_DATA_HOME/code-typing`).
+pub fn data_dir() -> Option<PathBuf> {
+    let base = std::env::var_os("XDG_DATA_HOME")
+        .filter(|v| !v.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| {
+            std::env::var_os("HOME")
+                .filter(|v| !v.is_empty())
+                .map(|home| PathBuf::from(home).join(".local").join("share"))
+        })?;
+    Some(base.join("code-typing"))
+}
+
+/// Identify a sample by its content (64-bit FNV-1a, hex). Unlike an index this
+/// survives samples being added or reordered, and unlike `DefaultHasher` it is
+/// stable across Rust releases.
+pub fn sample_id(code: &str) -> String {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in code.bytes() {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    format!("{hash:016x}")
+}
+
+/// Seconds since the Unix epoch.
+pub fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Days since the Unix epoch, by the UTC calendar.
+pub fn today() -> u64 {
+    unix_now() / 86_400
+}
+
+/// Format a Unix timestamp as a UTC calendar date, `YYYY-MM-DD`.
+pub fn format_date(timestamp: u64) -> String {
+    let (y, m, d) = civil_from_days((timestamp / 86_400) as i64);
+    format!("{y:04}-{m:02}-{d:02}")
+}
+
+/// Parse a `YYYY-MM-DD` date as the Unix timestamp of its start (UTC).
+pub fn parse_date(date: &str) -> Option<u64> {
+    let mut parts = date.splitn(3, '-');
+    let y: i64 = parts.next()?.parse().ok()?;
+    let m: u32 = parts.next()?.parse().ok()?;
+    let d: u32 = parts.next()?.parse().ok()?;
+    let days = days_from_civil(y, m, d)?;
+    u64::try_from(days).ok().map(|days| days * 86_400)
+}
+
+/// Convert a proleptic Gregorian date to days since 1970-01-01, rejecting dates
+/// that do not exist (Howard Hinnant's `days_from_civil`).
+fn days_from_civil(y: i64, m: u32, d: u32) -> Option<i64> {
+    if !(1..=12).contains(&m) || d == 0 {
+        return None;
+    }
+    let y = if m <= 2 { y - 1 } else { y };
+    let era = y.div_euclid(400);
+    let yoe = y.rem_euclid(400);
+    let mp = i64::from((m + 9) % 12);
+    let doy = (153 * mp + 2) / 5 + i64::from(d) - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146_097 + doe - 719_468;
+    // Out-of-range days (Feb 30th …) roll into the next month; catch them here.
+    let (_, cm, cd) = civil_from_days(days);
+    (cm == m && cd == d).then_some(days)
+}
+
+/// Convert days since 1970-01-01 to a proleptic Gregorian `(year, month, day)`
+/// (Howard Hinnant's `civil_from_days`).
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let y = yoe + era * 400 + i64::from(m <= 2);
+    (y, m, d)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::test_util::TempDir;
+
+    fn record(wpm: f32) -> RunRecord {
+        RunRecord {
+            timestamp: 1_700_000_000,
+            language: Language::Rust,
+            sample_id: sample_id("fn main() {}"),
+            wpm,
+            accuracy: 97.5,
+            duration_secs: 12.0,
+            keystrokes: 14,
+            errors: 1,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn appended_runs_survive_a_reload() {
+        let dir = TempDir::new("roundtrip");
+        let path = dir.join(HISTORY_FILE);
+        let mut history = History::open(path.clone()).unwrap();
+        assert!(history.runs.is_empty());
+        history.append(record(50.0)).unwrap();
+        history.append(record(60.0)).unwrap();
+
+        let reloaded = History::open(path.clone()).unwrap();
+        assert_eq!(reloaded.runs, vec![record(50.0), record(60.0)]);
+    }
+
+    #[test]
+    fn a_torn_last_line_is_skipped_and_repaired() {
+        let dir = TempDir::new("torn");
+        let path = dir.join(HISTORY_FILE);
+        let mut history = History::open(path.clone()).unwrap();
+        history.append(record(50.0)).unwrap();
+        // Simulate a crash halfway through writing the next record.
+        let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+        file.write_all(b"{\"timestamp\":17").unwrap();
+
+        let mut history = History::open(path.clone()).unwrap();
+        assert_eq!(history.runs.len(), 1);
+        history.append(record(70.0)).unwrap();
+        let reloaded = History::open(path.clone()).unwrap();
+        assert_eq!(reloaded.runs, vec![record(50.0), record(70.0)]);
+    }
+
+    #[test]
+    fn personal_bests_take_the_max_of_each_stat() {
+        let mut history = History::default();
+        assert_eq!(history.best_for_sample(&sample_id("fn main() {}")), None);
+        history.append(record(50.0)).unwrap();
+        let mut accurate = record(40.0);
+        accurate.accuracy = 100.0;
+        history.append(accurate).unwrap();
+
+        let best = PersonalBest {
+            wpm: 50.0,
+            accuracy: 100.0,
+        };
+        assert_eq!(
+            history.best_for_sample(&sample_id("fn main() {}")),
+            Some(best)
+        );
+        assert_eq!(history.best_for_language(Language::Rust), Some(best));
+        assert_eq!(history.best_for_language(Language::Go), None);
+    }
+
+    #[test]
+    fn streaming_runs_are_no_personal_best() {
+        let mut history = History::default();
+        history.append(record(50.0)).unwrap();
+        let mut timed = record(80.0);
+        timed.mode = Mode::Timed(30);
+        timed.sample_id = String::new();
+        history.append(timed).unwrap();
+        let mut streak = record(90.0);
+        streak.mode = Mode::SuddenDeath;
+        streak.sample_id = String::new();
+        streak.streak = Some(400);
+        history.append(streak).unwrap();
+
+        let best = history.best_for_language(Language::Rust).unwrap();
+        assert_eq!(best.wpm, 50.0);
+        assert_eq!(history.best_streak(Language::Rust), Some(400));
+    }
+
+    #[test]
+    fn dates_format_in_utc() {
+        assert_eq!(format_date(0), "1970-01-01");
+        assert_eq!(format_date(951_782_400), "2000-02-29");
+        assert_eq!(format_date(1_700_000_000), "2023-11-14");
+    }
+
+    #[test]
+    fn dates_parse_back_to_the_start_of_the_day() {
+        assert_eq!(parse_date("1970-01-01"), Some(0));
+        assert_eq!(parse_date("2000-02-29"), Some(951_782_400));
+        assert_eq!(format_date(parse_date("2023-11-14").unwrap()), "2023-11-14");
+        assert_eq!(parse_date("2023-02-29"), None);
+        assert_eq!(parse_date("2023-13-01"), None);
+        assert_eq!(parse_date("yesterday"), None);
+    }
+
+    #[test]
+    fn sample_id_is_stable() {
+        assert_eq!(sample_id(""), "cbf29ce484222325");
+        assert_ne!(sample_id("a"), sample_id("b"));
+    }
+}
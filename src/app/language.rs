@@ -5,7 +5,9 @@
 //! by `LanguageSpec`, so adding a language is almost entirely data: append a sample
 //! module, define a `LanguageSpec`, and add it to `Language` + `ALL`.
 
-use super::samples;
+use serde::{Deserialize, Serialize};
+
+use super::samples::{self, Sample};
 
 /// How a single quote `'` should be tokenized for a language.
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -44,12 +46,19 @@ pub struct LanguageSpec {
     pub decorators: bool,
     /// Spaces inserted for a Tab keystroke.
     pub indent_spaces: usize,
-    pub samples: &'static [(&'static str, &'static str)],
+    /// Source file extensions (without the dot), used to pick the language of loaded snippets.
+    pub extensions: &'static [&'static str],
+    /// Keywords that introduce a function definition (`fn`, `def` …), used to cut
+    /// functions out of a repository. Empty for C-style languages, where a
+    /// definition is recognised by its shape instead.
+    pub function_keywords: &'static [&'static str],
+    pub samples: &'static [Sample],
 }
 
 /// Supported languages, in menu order.
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default, Serialize, Deserialize)]
 pub enum Language {
+    #[default]
     Rust,
     Python,
     JavaScript,
@@ -96,6 +105,24 @@ impl Language {
     pub fn name(self) -> &'static str {
         self.spec().name
     }
+
+    /// The language of a source file with extension `ext` (without the dot).
+    pub fn from_extension(ext: &str) -> Option<Language> {
+        ALL.iter().copied().find(|l| {
+            l.spec()
+                .extensions
+                .iter()
+                .any(|e| e.eq_ignore_ascii_case(ext))
+        })
+    }
+
+    /// Look a language up by its display name or identifier, ignoring case
+    /// (`rust`, `c++`, `cpp`, `JavaScript` …), as typed on the command line.
+    pub fn from_name(name: &str) -> Option<Language> {
+        ALL.iter().copied().find(|l| {
+            l.name().eq_ignore_ascii_case(name) || format!("{l:?}").eq_ignore_ascii_case(name)
+        })
+    }
 }
 
 // ── Rust ────────────────────────────────────────────────────────────────────
@@ -190,6 +217,8 @@ static RUST: LanguageSpec = LanguageSpec {
     preprocessor: false,
     decorators: false,
     indent_spaces: 4,
+    extensions: &["rs"],
+    function_keywords: &["fn"],
     samples: samples::rust::SAMPLES,
 };
 
@@ -241,6 +270,8 @@ static PYTHON: LanguageSpec = LanguageSpec {
     preprocessor: false,
     decorators: true,
     indent_spaces: 4,
+    extensions: &["py", "pyw"],
+    function_keywords: &["def"],
     samples: samples::python::SAMPLES,
 };
 
@@ -310,6 +341,8 @@ static JAVASCRIPT: LanguageSpec = LanguageSpec {
     preprocessor: false,
     decorators: false,
     indent_spaces: 2,
+    extensions: &["js", "mjs", "cjs", "jsx"],
+    function_keywords: &["function"],
     samples: samples::javascript::SAMPLES,
 };
 
@@ -398,6 +431,8 @@ static TYPESCRIPT: LanguageSpec = LanguageSpec {
     preprocessor: false,
     decorators: true,
     indent_spaces: 2,
+    extensions: &["ts", "mts", "cts", "tsx"],
+    function_keywords: &["function"],
     samples: samples::typescript::SAMPLES,
 };
 
@@ -471,6 +506,8 @@ static GO: LanguageSpec = LanguageSpec {
     preprocessor: false,
     decorators: false,
     indent_spaces: 4,
+    extensions: &["go"],
+    function_keywords: &["func"],
     samples: samples::go::SAMPLES,
 };
 
@@ -569,6 +606,8 @@ static JAVA: LanguageSpec = LanguageSpec {
     preprocessor: false,
     decorators: true,
     indent_spaces: 4,
+    extensions: &["java"],
+    function_keywords: &[],
     samples: samples::java::SAMPLES,
 };
 
@@ -669,6 +708,8 @@ static KOTLIN: LanguageSpec = LanguageSpec {
     preprocessor: false,
     decorators: true,
     indent_spaces: 4,
+    extensions: &["kt", "kts"],
+    function_keywords: &["fun"],
     samples: samples::kotlin::SAMPLES,
 };
 
@@ -779,6 +820,8 @@ static SWIFT: LanguageSpec = LanguageSpec {
     preprocessor: false,
     decorators: true,
     indent_spaces: 4,
+    extensions: &["swift"],
+    function_keywords: &["func", "init"],
     samples: samples::swift::SAMPLES,
 };
 
@@ -821,6 +864,8 @@ static C: LanguageSpec = LanguageSpec {
     preprocessor: true,
     decorators: false,
     indent_spaces: 4,
+    extensions: &["c", "h"],
+    function_keywords: &[],
     samples: samples::c::SAMPLES,
 };
 
@@ -937,6 +982,8 @@ static CPP: LanguageSpec = LanguageSpec {
     preprocessor: true,
     decorators: false,
     indent_spaces: 4,
+    extensions: &["cpp", "cc", "cxx", "hpp", "hh", "hxx"],
+    function_keywords: &[],
     samples: samples::cpp::SAMPLES,
 };
 
@@ -961,4 +1008,21 @@ mod tests {
         assert_eq!(Language::Cpp.name(), "C++");
         assert_eq!(Language::JavaScript.name(), "JavaScript");
     }
+
+    #[test]
+    fn languages_parse_from_either_name() {
+        assert_eq!(Language::from_name("rust"), Some(Language::Rust));
+        assert_eq!(Language::from_name("C++"), Some(Language::Cpp));
+        assert_eq!(Language::from_name("cpp"), Some(Language::Cpp));
+        assert_eq!(Language::from_name("cobol"), None);
+    }
+
+    #[test]
+    fn languages_are_detected_from_extensions() {
+        assert_eq!(Language::from_extension("rs"), Some(Language::Rust));
+        assert_eq!(Language::from_extension("h"), Some(Language::C));
+        assert_eq!(Language::from_extension("HPP"), Some(Language::Cpp));
+        assert_eq!(Language::from_extension("tsx"), Some(Language::TypeScript));
+        assert_eq!(Language::from_extension("md"), None);
+    }
 }
@@ -0,0 +1,615 @@
+//! The samples the game draws from: the built-in sets from `samples/`, replaced
+//! per language by any snippets, packs, repository functions or recent changes
+//! loaded at startup (`--snippets <dir>`, `--pack <file>`, `--repo <path>`,
+//! `--git-history <path>`), or by a single file or stdin (`code-typing <file>`).
+//!
+//! Loaded code longer than the window size (`--window <lines>`) is split into
+//! consecutive samples at blank lines or top-level declarations, and each part
+//! remembers that the next one continues it.
+//!
+//! A pack is a TOML or JSON file of [`Sample`]s. The language can be given once
+//! for the whole pack and overridden per sample:
+//!
+//! ```toml
+//! language = "rust"
+//!
+//! [[samples]]
+//! title = "Builder pattern"
+//! tags = ["idiom", "api"]
+//! difficulty = 2
+//! source = "platform team"
+//! code = '''
+//! let client = Client::builder().timeout(secs(5)).build()?;
+//! '''
+//! ```
+
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io::{self, IsTerminal, Read};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use super::difficulty;
+use super::extract;
+use super::language::{Language, ALL};
+use super::samples::Sample;
+
+/// The samples of every language.
+#[derive(Clone, Debug)]
+pub struct Library {
+    samples: HashMap<Language, Vec<Sample>>,
+    /// Languages whose built-in samples were replaced by loaded ones.
+    custom: Vec<Language>,
+    /// Longest loaded sample, in lines, before it is split; 0 never splits.
+    window: usize,
+    /// `(language, index)` of samples whose code continues in the next sample.
+    continued: HashSet<(Language, usize)>,
+}
+
+/// Default for [`Library::set_window`].
+pub const DEFAULT_WINDOW: usize = 30;
+
+impl Default for Library {
+    /// Just the built-in samples.
+    fn default() -> Self {
+        // Scored once, however many games are started.
+        static BUILTIN: OnceLock<HashMap<Language, Vec<Sample>>> = OnceLock::new();
+        let samples = BUILTIN.get_or_init(|| {
+            ALL.iter()
+                .map(|&l| {
+                    let samples = l.spec().samples.iter().map(|s| rated(l, s.clone()));
+                    (l, samples.collect())
+                })
+                .collect()
+        });
+        Library {
+            samples: samples.clone(),
+            custom: Vec::new(),
+            window: DEFAULT_WINDOW,
+            continued: HashSet::new(),
+        }
+    }
+}
+
+/// A pack file as written by hand.
+#[derive(Deserialize)]
+struct Pack {
+    /// Default language of the samples, by name (`rust`, `c++` …).
+    language: Option<String>,
+    samples: Vec<PackSample>,
+}
+
+#[derive(Deserialize)]
+struct PackSample {
+    language: Option<String>,
+    #[serde(flatten)]
+    sample: Sample,
+}
+
+impl Library {
+    /// Load every file under `dir` (recursively, skipping hidden entries):
+    /// `.toml` and `.json` files with a top-level `samples` as packs, source
+    /// files of a supported language (by extension) as one sample each, without
+    /// expected output.
+    pub fn add_snippets(&mut self, dir: &Path) -> io::Result<()> {
+        let mut files = Vec::new();
+        collect_files(dir, &mut files)?;
+        files.sort();
+
+        let mut found = false;
+        for path in files {
+            let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+            if ext == "toml" || ext == "json" {
+                // Other config files (Cargo.toml, package.json …) often sit beside code
+                if fs::read_to_string(&path).is_ok_and(|text| is_pack(&text, &path)) {
+                    self.add_pack(&path)?;
+                    found = true;
+                }
+                continue;
+            }
+            let Some(language) = Language::from_extension(ext) else {
+                continue;
+            };
+            // Binary or non-UTF-8 files are not something to type; skip them.
+            let Ok(text) = fs::read_to_string(&path) else {
+                continue;
+            };
+            let code = normalize(&text, language.spec().indent_spaces);
+            if code.is_empty() {
+                continue;
+            }
+            let relative = path.strip_prefix(dir).unwrap_or(&path);
+            let mut sample = Sample::from_code(relative.display().to_string(), code);
+            sample.source = Some(path.display().to_string().into());
+            self.add_custom(language, sample);
+            found = true;
+        }
+
+        if !found {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                "no packs or source files of a supported language",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Load the samples of a TOML or JSON pack file.
+    pub fn add_pack(&mut self, path: &Path) -> io::Result<()> {
+        for (language, sample) in load_pack(path)? {
+            self.add_custom(language, sample);
+        }
+        Ok(())
+    }
+
+    /// Cut every function-sized chunk out of the source files of the repository
+    /// at `root` (see [`extract`]) and add each as a sample.
+    pub fn add_repo(&mut self, root: &Path) -> io::Result<()> {
+        let mut found = false;
+        for file in extract::repo_files(root)? {
+            let Some(language) = file
+                .extension()
+                .and_then(|e| e.to_str())
+                .and_then(Language::from_extension)
+            else {
+                continue;
+            };
+            let Ok(text) = fs::read_to_string(root.join(&file)) else {
+                continue;
+            };
+            for function in extract::functions(&text, language) {
+                let code = normalize(&function.code, language.spec().indent_spaces);
+                let mut sample = Sample::from_code(function.signature, code);
+                sample.source = Some(format!("{}:{}", file.display(), function.line).into());
+                self.add_custom(language, sample);
+                found = true;
+            }
+        }
+
+        if !found {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                "no functions found in files of a supported language",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Add the code recently added to the repository at `root` (see
+    /// [`extract::recent_changes`]), titled by commit subject and file.
+    pub fn add_git_history(
+        &mut self,
+        root: &Path,
+        author: Option<&str>,
+        since: Option<&str>,
+    ) -> io::Result<()> {
+        let changes = extract::recent_changes(root, author, since)?;
+        if changes.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                "no matching changes to files of a supported language",
+            ));
+        }
+        for change in changes {
+            let code = normalize(&change.code, change.language.spec().indent_spaces);
+            let title = format!("{} · {}", change.subject, change.path);
+            let mut sample = Sample::from_code(title, code);
+            sample.source =
+                Some(format!("{} {}:{}", change.commit, change.path, change.line).into());
+            self.add_custom(change.language, sample);
+        }
+        Ok(())
+    }
+
+    /// Split samples added from now on into parts of at most `lines` lines
+    /// (0 keeps them whole).
+    pub fn set_window(&mut self, lines: usize) {
+        self.window = lines;
+    }
+
+    /// Add a loaded sample, split into parts if it is longer than the window.
+    /// The first one of a language replaces its built-ins.
+    pub fn add_custom(&mut self, language: Language, sample: Sample) {
+        let samples = self.samples.entry(language).or_default();
+        if !self.custom.contains(&language) {
+            self.custom.push(language);
+            samples.clear();
+        }
+
+        let parts = match self.window {
+            0 => Vec::new(),
+            lines => windows(&sample.code, lines),
+        };
+        if parts.len() < 2 {
+            samples.push(rated(language, sample));
+            return;
+        }
+        let lines: Vec<&str> = sample.code.lines().collect();
+        let count = parts.len();
+        for (i, range) in parts.into_iter().enumerate() {
+            let mut part = sample.clone();
+            part.title =
+                format!("{} · lines {}–{}", sample.title, range.start + 1, range.end).into();
+            part.code = lines[range].join("\n").into();
+            if i + 1 < count {
+                self.continued.insert((language, samples.len()));
+            }
+            samples.push(rated(language, part));
+        }
+    }
+
+    /// The sample that continues sample `index` of `language`, if it was split.
+    pub fn next_part(&self, language: Language, index: usize) -> Option<usize> {
+        self.continued
+            .contains(&(language, index))
+            .then(|| index + 1)
+    }
+
+    pub fn samples(&self, language: Language) -> &[Sample] {
+        self.samples.get(&language).map_or(&[], Vec::as_slice)
+    }
+
+    /// Whether `language` plays loaded samples instead of the built-in ones.
+    pub fn is_custom(&self, language: Language) -> bool {
+        self.custom.contains(&language)
+    }
+}
+
+/// Score the difficulty of a sample that was not given one.
+fn rated(language: Language, mut sample: Sample) -> Sample {
+    if sample.difficulty.is_none() {
+        sample.difficulty = Some(difficulty::score(&sample.code, language.spec()));
+    }
+    sample
+}
+
+/// Parse a pack file (TOML, or JSON with a `.json` extension) into samples with
+/// their languages. Code is normalized like snippet files.
+pub fn load_pack(path: &Path) -> io::Result<Vec<(Language, Sample)>> {
+    let mut samples = read_pack(&fs::read_to_string(path)?, path)?;
+    for (language, sample) in &mut samples {
+        sample.code = normalize(&sample.code, language.spec().indent_spaces).into();
+        // Nothing to type: a run over it would end before it starts
+        if sample.code.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("sample `{}` has no code", sample.title),
+            ));
+        }
+    }
+    Ok(samples)
+}
+
+/// Whether the `text` of the file at `path` is meant as a pack: TOML (or JSON
+/// with a `.json` extension) with a top-level `samples`. It may still be invalid.
+fn is_pack(text: &str, path: &Path) -> bool {
+    if path.extension().is_some_and(|e| e == "json") {
+        serde_json::from_str::<serde_json::Value>(text).is_ok_and(|v| v.get("samples").is_some())
+    } else {
+        toml::from_str::<toml::Table>(text).is_ok_and(|t| t.contains_key("samples"))
+    }
+}
+
+/// Parse the `text` of the pack file at `path` as written, without normalizing.
+pub fn read_pack(text: &str, path: &Path) -> io::Result<Vec<(Language, Sample)>> {
+    let invalid = |msg: String| io::Error::new(io::ErrorKind::InvalidData, msg);
+    let pack: Pack = if path.extension().is_some_and(|e| e == "json") {
+        serde_json::from_str(text).map_err(|e| invalid(e.to_string()))?
+    } else {
+        toml::from_str(text).map_err(|e| invalid(e.to_string()))?
+    };
+
+    let mut samples = Vec::new();
+    for PackSample { language, sample } in pack.samples {
+        let name = language
+            .or_else(|| pack.language.clone())
+            .ok_or_else(|| invalid(format!("sample `{}` has no language", sample.title)))?;
+        let language = Language::from_name(&name).ok_or_else(|| {
+            invalid(format!(
+                "sample `{}`: unknown language `{name}`",
+                sample.title
+            ))
+        })?;
+        samples.push((language, sample));
+    }
+    Ok(samples)
+}
+
+/// Read one snippet from `path`, or from stdin if it is `-`. The language is
+/// `language` if given, otherwise guessed from the file extension.
+pub fn load_file(path: &Path, language: Option<Language>) -> io::Result<(Language, Sample)> {
+    let invalid = |msg: String| io::Error::new(io::ErrorKind::InvalidInput, msg);
+    let stdin = path == Path::new("-");
+    let text = if stdin {
+        if io::stdin().is_terminal() {
+            return Err(invalid("nothing was piped to stdin".to_string()));
+        }
+        // Read everything now: once stdin is exhausted the terminal takes keys
+        // from the TTY instead (crossterm opens /dev/tty when stdin is a pipe).
+        let mut text = String::new();
+        io::stdin().read_to_string(&mut text)?;
+        text
+    } else {
+        fs::read_to_string(path)?
+    };
+
+    let language = match language {
+        Some(language) => language,
+        None => path
+            .extension()
+            .and_then(|e| e.to_str())
+            .and_then(Language::from_extension)
+            .ok_or_else(|| invalid("cannot tell the language; pass --lang".to_string()))?,
+    };
+    let code = normalize(&text, language.spec().indent_spaces);
+    if code.is_empty() {
+        return Err(invalid("there is no code to type".to_string()));
+    }
+    let title = match path.file_name() {
+        Some(name) if !stdin => name.to_string_lossy().into_owned(),
+        _ => "stdin".to_string(),
+    };
+    let mut sample = Sample::from_code(title, code);
+    if !stdin {
+        sample.source = Some(path.display().to_string().into());
+    }
+    Ok((language, sample))
+}
+
+/// Line ranges of at most `max_lines` lines covering the non-blank parts of
+/// `code`. Each window ends at the last blank line, or failing that before the
+/// last top-level line (no indentation, not a closing bracket), in the second
+/// half of the room it has; only code with neither is cut mid-block.
+pub fn windows(code: &str, max_lines: usize) -> Vec<Range<usize>> {
+    let lines: Vec<&str> = code.lines().collect();
+    let blank = |i: usize| lines[i].trim().is_empty();
+    let top_level = |i: usize| {
+        !lines[i].starts_with(char::is_whitespace) && !lines[i].starts_with(['}', ')', ']'])
+    };
+
+    let mut windows = Vec::new();
+    let mut start = 0;
+    loop {
+        while start < lines.len() && blank(start) {
+            start += 1;
+        }
+        if start == lines.len() {
+            return windows;
+        }
+        let end = if lines.len() - start <= max_lines {
+            lines.len()
+        } else {
+            let room = (start + max_lines / 2).max(start + 1)..=start + max_lines;
+            room.clone()
+                .rev()
+                .find(|&i| blank(i))
+                .or_else(|| room.clone().rev().find(|&i| top_level(i)))
+                .unwrap_or(*room.end())
+        };
+        let mut last = end;
+        while blank(last - 1) {
+            last -= 1;
+        }
+        windows.push(start..last);
+        start = end;
+    }
+}
+
+fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        if entry.file_name().to_string_lossy().starts_with('.') {
+            continue;
+        }
+        let path = entry.path();
+        if entry.file_type()?.is_dir() {
+            collect_files(&path, files)?;
+        } else {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Make source text typeable: Unix line endings, tabs expanded to the language's
+/// indent width (the Tab key inserts spaces), no trailing whitespace, and no
+/// leading or trailing blank lines.
+pub fn normalize(text: &str, indent_spaces: usize) -> String {
+    let tab = " ".repeat(indent_spaces);
+    let lines: Vec<String> = text
+        .lines()
+        .map(|line| line.replace('\t', &tab).trim_end().to_string())
+        .collect();
+    let start = lines
+        .iter()
+        .position(|l| !l.is_empty())
+        .unwrap_or(lines.len());
+    let end = lines
+        .iter()
+        .rposition(|l| !l.is_empty())
+        .map_or(start, |i| i + 1);
+    lines[start..end].join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::test_util::TempDir;
+
+    #[test]
+    fn source_text_is_normalized_for_typing() {
+        assert_eq!(
+            normalize("\r\n\nfn main() {\r\n\tlet x = 1;  \r\n}\n\n", 4),
+            "fn main() {\n    let x = 1;\n}"
+        );
+        assert_eq!(normalize(" \n\t\n", 4), "");
+    }
+
+    #[test]
+    fn long_code_splits_at_blank_lines_then_declarations() {
+        let code = "a\n  b\n\nc\n  d\n  e\nf\n  g\n  h\n  i\n  j\n  k";
+        assert_eq!(windows(code, 5), [0..2, 3..6, 6..11, 11..12]);
+        assert_eq!(windows(code, 20), vec![0..12]);
+        assert_eq!(windows("\n\n", 5), []);
+    }
+
+    #[test]
+    fn split_samples_link_to_their_next_part() {
+        let code = (1..=25)
+            .map(|i| format!("fn f{i}() {{}}\n"))
+            .collect::<String>();
+        let mut library = Library::default();
+        library.set_window(10);
+        library.add_custom(Language::Rust, Sample::from_code("big.rs", code.clone()));
+        library.add_custom(Language::Rust, Sample::from_code("small.rs", "fn g() {}"));
+
+        let titles: Vec<&str> = library
+            .samples(Language::Rust)
+            .iter()
+            .map(|s| s.title.as_ref())
+            .collect();
+        assert_eq!(
+            titles,
+            [
+                "big.rs · lines 1–10",
+                "big.rs · lines 11–20",
+                "big.rs · lines 21–25",
+                "small.rs"
+            ]
+        );
+        assert_eq!(library.next_part(Language::Rust, 0), Some(1));
+        assert_eq!(library.next_part(Language::Rust, 1), Some(2));
+        assert_eq!(library.next_part(Language::Rust, 2), None);
+        assert_eq!(library.next_part(Language::Rust, 3), None);
+
+        library.set_window(0);
+        library.add_custom(Language::Go, Sample::from_code("big.go", code));
+        assert_eq!(library.samples(Language::Go).len(), 1);
+    }
+
+    #[test]
+    fn snippets_replace_builtins_only_for_their_languages() {
+        let dir = TempDir::new("snippets");
+        fs::create_dir_all(dir.join("nested")).unwrap();
+        fs::create_dir_all(dir.join(".git")).unwrap();
+        fs::write(dir.join("a.py"), "print(1)\n").unwrap();
+        fs::write(dir.join("nested").join("b.py"), "print(2)\n").unwrap();
+        fs::write(dir.join(".git").join("c.py"), "print(3)\n").unwrap();
+        fs::write(dir.join("notes.md"), "# not code\n").unwrap();
+        fs::write(
+            dir.join("package.json"),
+            r#"{"name": "app", "version": "1.0.0"}"#,
+        )
+        .unwrap();
+        fs::write(dir.join("Cargo.toml"), "[package]\nname = \"app\"\n").unwrap();
+        fs::write(dir.join("tsconfig.json"), "{ // comments are not JSON\n}").unwrap();
+
+        let mut library = Library::default();
+        library.add_snippets(&dir).unwrap();
+        let python: Vec<&str> = library
+            .samples(Language::Python)
+            .iter()
+            .map(|s| s.code.as_ref())
+            .collect();
+        assert_eq!(python, ["print(1)", "print(2)"]);
+        assert_eq!(library.samples(Language::Python)[1].title, "nested/b.py");
+        assert!(library.is_custom(Language::Python));
+        assert_eq!(library.samples(Language::Python)[0].difficulty, Some(1));
+        assert!(!library.is_custom(Language::Rust));
+        assert_eq!(
+            library.samples(Language::Rust).len(),
+            Language::Rust.spec().samples.len()
+        );
+
+        fs::remove_dir_all(dir.join("nested")).unwrap();
+        fs::remove_file(dir.join("a.py")).unwrap();
+        assert!(Library::default().add_snippets(&dir).is_err());
+    }
+
+    #[test]
+    fn packs_load_from_toml_and_json() {
+        let dir = TempDir::new("packs");
+        let toml = dir.join("team.toml");
+        fs::write(
+            &toml,
+            r#"language = "go"
+
+[[samples]]
+title = "Loop"
+tags = ["basics"]
+difficulty = 1
+code = '''
+for i := 0; i < 3; i++ {
+	fmt.Println(i)
+}
+'''
+output = "0\n1\n2"
+
+[[samples]]
+title = "Hello"
+language = "python"
+source = "docs"
+code = "print('hi')"
+"#,
+        )
+        .unwrap();
+        let samples = load_pack(&toml).unwrap();
+        assert_eq!(samples.len(), 2);
+        let (language, sample) = &samples[0];
+        assert_eq!(*language, Language::Go);
+        assert_eq!(sample.title, "Loop");
+        assert_eq!(
+            sample.code,
+            "for i := 0; i < 3; i++ {\n    fmt.Println(i)\n}"
+        );
+        assert_eq!(sample.output, "0\n1\n2");
+        assert_eq!(sample.tags.as_ref(), ["basics"]);
+        assert_eq!(sample.difficulty, Some(1));
+        assert_eq!(samples[1].0, Language::Python);
+        assert_eq!(samples[1].1.source.as_deref(), Some("docs"));
+
+        let json = dir.join("more.json");
+        fs::write(
+            &json,
+            r#"{"samples": [{"language": "c++", "title": "Hi", "code": "int x;"}]}"#,
+        )
+        .unwrap();
+        let mut library = Library::default();
+        library.add_snippets(&dir).unwrap();
+        assert_eq!(library.samples(Language::Go).len(), 1);
+        assert_eq!(library.samples(Language::Cpp)[0].title, "Hi");
+
+        fs::write(&json, r#"{"samples": [{"title": "Hi", "code": "int x;"}]}"#).unwrap();
+        assert!(load_pack(&json).is_err()); // no language anywhere
+        fs::write(
+            &json,
+            r#"{"language": "c", "samples": [{"title": "Blank", "code": " \n\t\n"}]}"#,
+        )
+        .unwrap();
+        let error = load_pack(&json).unwrap_err();
+        assert_eq!(error.to_string(), "sample `Blank` has no code");
+    }
+
+    #[test]
+    fn single_files_take_their_language_from_the_extension_or_flag() {
+        let dir = TempDir::new("file");
+        let file = dir.join("main.go");
+        fs::write(&file, "\tfmt.Println(1)\n").unwrap();
+        let (language, sample) = load_file(&file, None).unwrap();
+        assert_eq!(language, Language::Go);
+        assert_eq!(sample.title, "main.go");
+        assert_eq!(sample.code, "    fmt.Println(1)");
+
+        let file = dir.join("snippet.txt");
+        fs::write(&file, "print(1)\n").unwrap();
+        assert!(load_file(&file, None).is_err());
+        let (language, sample) = load_file(&file, Some(Language::Python)).unwrap();
+        assert_eq!(language, Language::Python);
+        assert_eq!(sample.source.as_deref(), file.to_str());
+
+        fs::write(&file, "\n\n").unwrap();
+        assert!(load_file(&file, Some(Language::Python)).is_err());
+    }
+}
@@ -1,9 +1,21 @@
+pub mod difficulty;
+pub mod extract;
 pub mod game;
+pub mod history;
 pub mod language;
+pub mod library;
+pub mod mode;
+pub mod replay;
+pub mod rng;
 pub mod samples;
 pub mod state;
+pub mod stats;
 pub mod syntax;
+#[cfg(test)]
+mod test_util;
 pub mod ui;
+pub mod validate;
+pub mod verify;
 
 pub use game::Game;
 pub use state::GameState;
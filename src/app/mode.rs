@@ -0,0 +1,64 @@
+//! Ways to play a run, picked in the menu.
+
+use serde::{Deserialize, Serialize};
+
+use super::history;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Mode {
+    /// Type one sample to the end.
+    #[default]
+    Sample,
+    /// Type samples back to back until this many seconds are up.
+    Timed(u64),
+    /// Type samples back to back until the first mistake.
+    SuddenDeath,
+    /// The scored attempt at the daily challenge of this day (days since the
+    /// Unix epoch). Not in [`MODES`]: it has its own menu entry.
+    Daily(u64),
+}
+
+/// Every mode, in the order the menu cycles through them.
+pub const MODES: [Mode; 6] = [
+    Mode::Sample,
+    Mode::Timed(15),
+    Mode::Timed(30),
+    Mode::Timed(60),
+    Mode::Timed(120),
+    Mode::SuddenDeath,
+];
+
+impl Mode {
+    pub fn name(self) -> String {
+        match self {
+            Mode::Sample => "single sample".to_string(),
+            Mode::Timed(secs) => format!("timed {secs}s"),
+            Mode::SuddenDeath => "sudden death".to_string(),
+            Mode::Daily(day) => format!("daily {}", history::format_date(day * 86_400)),
+        }
+    }
+
+    /// The mode after this one in [`MODES`], wrapping around.
+    pub fn next(self) -> Mode {
+        let i = MODES.iter().position(|&m| m == self).unwrap_or(0);
+        MODES[(i + 1) % MODES.len()]
+    }
+
+    /// How long a run lasts, if it is cut off by time.
+    pub fn time_limit(self) -> Option<u64> {
+        match self {
+            Mode::Timed(secs) => Some(secs),
+            Mode::Sample | Mode::SuddenDeath | Mode::Daily(_) => None,
+        }
+    }
+
+    /// Whether finishing a sample moves straight on to another.
+    pub fn streams(self) -> bool {
+        matches!(self, Mode::Timed(_) | Mode::SuddenDeath)
+    }
+
+    pub fn is_sample(&self) -> bool {
+        *self == Mode::Sample
+    }
+}
@@ -0,0 +1,458 @@
+//! Keystroke recordings and their playback.
+//!
+//! A [`Recording`] stores the sample that was typed plus every key pressed, each
+//! with its offset from the first keystroke. Finished runs are saved as JSON under
+//! `<data dir>/recordings/`, and a [`Player`] schedules a recording's keys against
+//! a (possibly sped-up or paused) clock so `Game` can feed them back through its
+//! normal input handling.
+//!
+//! The best recording of each sample is also kept under `recordings/best/` and
+//! raced as a [`Ghost`] whenever that sample comes up again.
+
+use crossterm::event::KeyCode;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::{self, OpenOptions};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use super::history;
+use super::language::Language;
+
+/// Playback speeds selectable during a replay.
+pub const SPEEDS: [u32; 3] = [1, 2, 4];
+
+/// How many recent runs are kept in `recordings/`; older ones are deleted as
+/// new ones are saved. Ghosts in `recordings/best/` are kept regardless.
+pub const KEEP_RECORDINGS: usize = 100;
+
+/// One recorded run: what was typed and how.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Recording {
+    /// Unix timestamp (seconds) of when the run was completed.
+    pub timestamp: u64,
+    pub language: Language,
+    pub code: String,
+    pub expected_output: String,
+    pub wpm: f32,
+    pub accuracy: f32,
+    /// `(ms since the first keystroke, key)` for every key that reached the typing screen.
+    pub events: Vec<(u64, KeyCode)>,
+    /// Typed with wrong keys held back (see `GameState::stop_on_error`), so
+    /// playback has to hold them back too.
+    #[serde(default, skip_serializing_if = "<&bool as std::ops::Not>::not")]
+    pub stop_on_error: bool,
+}
+
+impl Recording {
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let text = fs::read_to_string(path)?;
+        serde_json::from_str(&text).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    /// Write the recording to `path`, replacing it atomically.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let tmp = path.with_extension("json.tmp");
+        fs::write(&tmp, serde_json::to_string(self)?)?;
+        fs::rename(tmp, path)
+    }
+
+    /// Save under `<data dir>/recordings/`, returning the file written.
+    pub fn save_to_data_dir(&self) -> io::Result<PathBuf> {
+        let dir = history::data_dir()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no data directory"))?
+            .join("recordings");
+        self.save_in(&dir, KEEP_RECORDINGS)
+    }
+
+    /// Save as `<timestamp>-<language>.json` in `dir`, then delete the oldest
+    /// recordings there beyond the `keep` most recent. Runs finished within the
+    /// same second get `-2`, `-3` … appended rather than replacing each other.
+    fn save_in(&self, dir: &Path, keep: usize) -> io::Result<PathBuf> {
+        fs::create_dir_all(dir)?;
+        let stem = format!(
+            "{}-{}",
+            self.timestamp,
+            format!("{:?}", self.language).to_lowercase()
+        );
+        let mut count = 1;
+        let path = loop {
+            let path = match count {
+                1 => dir.join(format!("{stem}.json")),
+                n => dir.join(format!("{stem}-{n}.json")),
+            };
+            // Claim the name first, so a concurrent save cannot take it too
+            match OpenOptions::new().write(true).create_new(true).open(&path) {
+                Ok(_) => break path,
+                Err(e) if e.kind() == io::ErrorKind::AlreadyExists => count += 1,
+                Err(e) => return Err(e),
+            }
+        };
+        self.save(&path)?;
+
+        let mut saved: Vec<(u64, u32, PathBuf)> = fs::read_dir(dir)?
+            .filter_map(|entry| {
+                let path = entry.ok()?.path();
+                let name = path.file_name()?.to_str()?.strip_suffix(".json")?;
+                let timestamp = name.split('-').next()?.parse().ok()?;
+                let count = name.rsplit('-').next()?.parse().unwrap_or(1);
+                Some((timestamp, count, path))
+            })
+            .collect();
+        saved.sort();
+        for (_, _, old) in saved.iter().rev().skip(keep) {
+            fs::remove_file(old)?;
+        }
+        Ok(path)
+    }
+}
+
+/// The best recording of each sample (by net WPM), keyed by [`history::sample_id`].
+#[derive(Clone, Debug, Default)]
+pub struct Ghosts {
+    /// `None` keeps the ghosts in memory only (tests, or no data directory).
+    dir: Option<PathBuf>,
+    best: HashMap<String, Recording>,
+}
+
+impl Ghosts {
+    /// Load the best recordings from `<data dir>/recordings/best/`.
+    pub fn load() -> Self {
+        match history::data_dir() {
+            Some(dir) => Self::open(dir.join("recordings").join("best")),
+            None => Self::default(),
+        }
+    }
+
+    /// Load the best recordings stored in `dir`. Ghosts are an extra, so files
+    /// that cannot be read are skipped rather than reported.
+    pub fn open(dir: PathBuf) -> Self {
+        let best = fs::read_dir(&dir)
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| Recording::load(&entry.ok()?.path()).ok())
+            .map(|recording| (history::sample_id(&recording.code), recording))
+            .collect();
+        Ghosts {
+            dir: Some(dir),
+            best,
+        }
+    }
+
+    pub fn get(&self, sample_id: &str) -> Option<&Recording> {
+        self.best.get(sample_id)
+    }
+
+    /// Keep `recording` if it beats the stored best for its sample, returning
+    /// whether it did.
+    pub fn offer(&mut self, recording: &Recording) -> io::Result<bool> {
+        let id = history::sample_id(&recording.code);
+        if matches!(self.best.get(&id), Some(best) if best.wpm >= recording.wpm) {
+            return Ok(false);
+        }
+        if let Some(dir) = &self.dir {
+            recording.save(&dir.join(format!("{id}.json")))?;
+        }
+        self.best.insert(id, recording.clone());
+        Ok(true)
+    }
+}
+
+/// A best run reduced to where its cursor was over time.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Ghost {
+    pub wpm: f32,
+    /// `(ms since the first keystroke, input length)` after every recorded key.
+    steps: Vec<(u64, usize)>,
+}
+
+impl Ghost {
+    /// Work out the cursor positions from the recorded keys, moving the cursor
+    /// as `GameState::handle_input` does: Tab types the indent width, Enter
+    /// auto-indents, wrong keys are held back with stop on errors, and nothing
+    /// moves it once the end of the code is reached.
+    pub fn new(recording: &Recording) -> Self {
+        let code: Vec<char> = recording.code.chars().collect();
+        let indent = recording.language.spec().indent_spaces;
+        let mut len = 0;
+        // Type `chars` one by one, as the normal (not auto-indent) input does
+        let type_chars = |len: &mut usize, chars: &[char]| {
+            for &c in chars {
+                if code.get(*len) != Some(&c) && recording.stop_on_error {
+                    break;
+                }
+                *len += 1;
+                if *len >= code.len() {
+                    break;
+                }
+            }
+        };
+        let steps = recording
+            .events
+            .iter()
+            .map(|&(ms, key)| {
+                if len < code.len() {
+                    match key {
+                        KeyCode::Char(c) => type_chars(&mut len, &[c]),
+                        KeyCode::Tab => type_chars(&mut len, &vec![' '; indent]),
+                        KeyCode::Enter => {
+                            let indented = match code.get(len) {
+                                Some('\n') => code[len + 1..]
+                                    .iter()
+                                    .take_while(|&&c| c == ' ' || c == '\t')
+                                    .count(),
+                                _ => 0,
+                            };
+                            if indented > 0 {
+                                len = (len + 1 + indented).min(code.len());
+                            } else {
+                                type_chars(&mut len, &['\n']);
+                            }
+                        }
+                        KeyCode::Backspace => len = len.saturating_sub(1),
+                        _ => {}
+                    }
+                }
+                (ms, len)
+            })
+            .collect();
+        Ghost {
+            wpm: recording.wpm,
+            steps,
+        }
+    }
+
+    /// Input length of the ghost `ms` milliseconds into the run.
+    pub fn position(&self, ms: u64) -> usize {
+        let done = self.steps.partition_point(|&(at, _)| at <= ms);
+        done.checked_sub(1).map_or(0, |i| self.steps[i].1)
+    }
+}
+
+/// What the UI shows while a replay is running.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ReplayStatus {
+    pub speed: u32,
+    pub paused: bool,
+}
+
+/// Releases a recording's keys as playback time advances.
+pub struct Player {
+    events: Vec<(u64, KeyCode)>,
+    next: usize,
+    /// Playback position, in recording time.
+    position: Duration,
+    pub status: ReplayStatus,
+}
+
+impl Player {
+    pub fn new(recording: &Recording) -> Self {
+        Player {
+            events: recording.events.clone(),
+            next: 0,
+            position: Duration::ZERO,
+            status: ReplayStatus {
+                speed: 1,
+                paused: false,
+            },
+        }
+    }
+
+    /// Advance playback by `real` wall-clock time (scaled by the speed, nothing
+    /// while paused) and return the keys that became due, with their offsets.
+    pub fn advance(&mut self, real: Duration) -> Vec<(Duration, KeyCode)> {
+        if !self.status.paused {
+            self.position += real * self.status.speed;
+        }
+        let mut due = Vec::new();
+        while let Some(&(ms, key)) = self.events.get(self.next) {
+            let at = Duration::from_millis(ms);
+            if at > self.position {
+                break;
+            }
+            due.push((at, key));
+            self.next += 1;
+        }
+        due
+    }
+
+    /// Current playback position in recording time.
+    pub fn position(&self) -> Duration {
+        self.position
+    }
+
+    pub fn toggle_pause(&mut self) {
+        self.status.paused = !self.status.paused;
+    }
+
+    pub fn set_speed(&mut self, speed: u32) {
+        self.status.speed = speed;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::test_util::TempDir;
+
+    fn recording() -> Recording {
+        Recording {
+            timestamp: 1_700_000_000,
+            language: Language::Rust,
+            code: "fn".to_string(),
+            expected_output: String::new(),
+            wpm: 60.0,
+            accuracy: 100.0,
+            events: vec![
+                (0, KeyCode::Char('f')),
+                (400, KeyCode::Backspace),
+                (1000, KeyCode::Char('n')),
+            ],
+            stop_on_error: false,
+        }
+    }
+
+    #[test]
+    fn recordings_round_trip_through_a_file() {
+        let dir = TempDir::new("replay");
+        let path = dir.join("run.json");
+        recording().save(&path).unwrap();
+        assert_eq!(Recording::load(&path).unwrap(), recording());
+    }
+
+    #[test]
+    fn recordings_in_the_same_second_get_their_own_files() {
+        let dir = TempDir::new("same-second");
+        let first = recording().save_in(&dir, 2).unwrap();
+        let mut second = recording();
+        second.wpm = 70.0;
+        let second_path = second.save_in(&dir, 2).unwrap();
+        assert_ne!(first, second_path);
+        assert_eq!(Recording::load(&first).unwrap(), recording());
+        assert_eq!(Recording::load(&second_path).unwrap(), second);
+
+        // Pruning counts the newer of the two as more recent
+        let third = recording().save_in(&dir, 2).unwrap();
+        assert!(!first.exists());
+        assert!(second_path.exists() && third.exists());
+    }
+
+    #[test]
+    fn saving_keeps_only_the_most_recent_recordings() {
+        let dir = TempDir::new("recent");
+        fs::create_dir_all(dir.join("best")).unwrap();
+        fs::write(dir.join("best").join("ghost.json"), "{}").unwrap();
+        for timestamp in [30, 10, 20, 40] {
+            let mut run = recording();
+            run.timestamp = timestamp;
+            run.save_in(&dir, 2).unwrap();
+        }
+        let mut names: Vec<String> = fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect();
+        names.sort();
+        assert_eq!(names, ["30-rust.json", "40-rust.json", "best"]);
+    }
+
+    #[test]
+    fn ghosts_follow_the_recorded_cursor() {
+        let ghost = Ghost::new(&recording());
+        assert_eq!(ghost.position(0), 1);
+        assert_eq!(ghost.position(399), 1);
+        assert_eq!(ghost.position(400), 0); // backspaced
+        assert_eq!(ghost.position(5000), 1);
+    }
+
+    #[test]
+    fn ghosts_move_like_the_game_cursor() {
+        use crate::app::state::GameState;
+
+        let code = "fn f() {\n    g();\n}";
+        let rest = [
+            KeyCode::Char('n'),
+            KeyCode::Char(' '),
+            KeyCode::Char('f'),
+            KeyCode::Char('('),
+            KeyCode::Char(')'),
+            KeyCode::Char(' '),
+            KeyCode::Char('{'),
+            KeyCode::Enter,
+            KeyCode::Backspace,
+            KeyCode::Backspace,
+            KeyCode::Backspace,
+            KeyCode::Backspace,
+            KeyCode::Tab,
+            KeyCode::Char('g'),
+            KeyCode::Char('('),
+            KeyCode::Char(')'),
+            KeyCode::Char(';'),
+            KeyCode::Enter,
+            KeyCode::Char('}'),
+            KeyCode::Backspace,
+        ];
+        for stop_on_error in [false, true] {
+            // With stop on errors the typo is held back, so there is nothing to delete
+            let typo: &[KeyCode] = if stop_on_error {
+                &[KeyCode::Char('x')]
+            } else {
+                &[KeyCode::Char('x'), KeyCode::Backspace]
+            };
+            let keys = [&[KeyCode::Char('f')], typo, &rest].concat();
+            let mut recording = recording();
+            recording.code = code.to_string();
+            recording.stop_on_error = stop_on_error;
+            recording.events = (0..).zip(keys).collect();
+
+            let mut game = GameState::new();
+            game.stop_on_error = stop_on_error;
+            game.load_code(Language::Rust, code, "");
+            let ghost = Ghost::new(&recording);
+            for (ms, key) in recording.events {
+                match key {
+                    KeyCode::Char(c) => game.handle_input(c),
+                    KeyCode::Tab => game.handle_input('\t'),
+                    KeyCode::Enter => game.handle_input('\n'),
+                    KeyCode::Backspace => game.handle_backspace(),
+                    _ => {}
+                }
+                assert_eq!(ghost.position(ms), game.user_input_chars.len(), "{key:?}");
+            }
+            assert!(game.game_over);
+        }
+    }
+
+    #[test]
+    fn only_faster_runs_replace_a_ghost() {
+        let dir = TempDir::new("ghosts");
+        let mut ghosts = Ghosts::open(dir.to_path_buf());
+        assert!(ghosts.offer(&recording()).unwrap());
+        let mut slower = recording();
+        slower.wpm = 30.0;
+        assert!(!ghosts.offer(&slower).unwrap());
+
+        let reloaded = Ghosts::open(dir.to_path_buf());
+        assert_eq!(reloaded.get(&history::sample_id("fn")), Some(&recording()));
+    }
+
+    #[test]
+    fn player_releases_keys_on_schedule() {
+        let mut player = Player::new(&recording());
+        assert_eq!(player.advance(Duration::ZERO).len(), 1); // first key at 0ms
+        assert!(player.advance(Duration::from_millis(300)).is_empty());
+
+        player.set_speed(2);
+        let due = player.advance(Duration::from_millis(100)); // 300 + 2 * 100 = 500ms
+        assert_eq!(due, vec![(Duration::from_millis(400), KeyCode::Backspace)]);
+
+        player.toggle_pause();
+        assert!(player.advance(Duration::from_secs(10)).is_empty());
+        player.toggle_pause();
+        assert_eq!(player.advance(Duration::from_millis(250)).len(), 1);
+        assert!(player.advance(Duration::from_secs(10)).is_empty());
+    }
+}
@@ -0,0 +1,45 @@
+//! A small seedable random number generator for picks that must come out the
+//! same everywhere: the daily challenge, and sessions started with `--seed`.
+//!
+//! `rand`'s generators and range sampling may change between its versions, so
+//! a dependency bump would silently change both. SplitMix64 is a few lines of
+//! fixed arithmetic, which keeps every build on the same sequence.
+
+/// SplitMix64 (Steele, Lea and Flood), as used to seed xoshiro generators.
+#[derive(Clone, Debug)]
+pub struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    pub fn new(seed: u64) -> Self {
+        SplitMix64 { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..n`, for `n > 0`.
+    pub fn below(&mut self, n: usize) -> usize {
+        // Multiply-shift rather than `%`: no division, and the bias is negligible
+        ((u128::from(self.next_u64()) * n as u128) >> 64) as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_the_reference_sequence() {
+        let mut rng = SplitMix64::new(0);
+        assert_eq!(rng.next_u64(), 0xE220_A839_7B1D_CDAF);
+        assert_eq!(rng.next_u64(), 0x6E78_9E6A_A1B9_65F4);
+        assert!((0..100).all(|_| rng.below(7) < 7));
+    }
+}
@@ -1,5 +1,8 @@
 //! Per-language code sample collections. Each submodule exposes
-//! `SAMPLES: &[(&str, &str)]` = `(code, expected_output)`.
+//! `SAMPLES: &[Sample]`; packs loaded at runtime produce the same type.
+
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 
 pub mod c;
 pub mod cpp;
@@ -11,3 +14,61 @@ pub mod python;
 pub mod rust;
 pub mod swift;
 pub mod typescript;
+
+/// One piece of code to type, plus what is known about it. Built-in samples
+/// borrow static strings; samples from packs and files own theirs.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Sample {
+    pub title: Cow<'static, str>,
+    pub code: Cow<'static, str>,
+    /// What the code prints when run. Empty suppresses the output panel.
+    #[serde(default, skip_serializing_if = "str::is_empty")]
+    pub output: Cow<'static, str>,
+    /// The output differs from run to run (hash map order, timing …), so it
+    /// is shown as an example and not checked by `code-typing verify-output`.
+    #[serde(default, skip_serializing_if = "<&bool as std::ops::Not>::not")]
+    pub nondeterministic: bool,
+    #[serde(default, skip_serializing_if = "<[_]>::is_empty")]
+    pub tags: Cow<'static, [Cow<'static, str>]>,
+    /// Difficulty from 1 (easiest) to 5. Set by the author, or scored from the
+    /// code when the sample is added to a [`Library`](super::library::Library).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub difficulty: Option<u8>,
+    /// Where the code came from: an author, a URL, a file path …
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<Cow<'static, str>>,
+}
+
+impl Sample {
+    /// A built-in sample without tags, difficulty or attribution.
+    pub const fn new(title: &'static str, code: &'static str, output: &'static str) -> Self {
+        Sample {
+            title: Cow::Borrowed(title),
+            code: Cow::Borrowed(code),
+            output: Cow::Borrowed(output),
+            nondeterministic: false,
+            tags: Cow::Borrowed(&[]),
+            difficulty: None,
+            source: None,
+        }
+    }
+
+    /// Mark the output as differing from run to run.
+    pub const fn nondeterministic(mut self) -> Self {
+        self.nondeterministic = true;
+        self
+    }
+
+    /// An untitled sample that owns its code, e.g. one read from a file.
+    pub fn from_code(title: impl Into<String>, code: impl Into<String>) -> Self {
+        Sample {
+            title: Cow::Owned(title.into()),
+            code: Cow::Owned(code.into()),
+            output: Cow::Borrowed(""),
+            nondeterministic: false,
+            tags: Cow::Borrowed(&[]),
+            difficulty: None,
+            source: None,
+        }
+    }
+}
@@ -1,7 +1,15 @@
-use rand::Rng;
-use std::time::Instant;
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
 
+use super::difficulty::{Level, LEVELS};
+use super::history::{self, History, PersonalBest, RunRecord};
 use super::language::{Language, ALL};
+use super::library::Library;
+use super::mode::Mode;
+use super::replay::{Ghost, Ghosts, Recording, ReplayStatus};
+use super::rng::SplitMix64;
+use super::samples::Sample;
+use super::stats;
 
 /// Which screen the app is showing.
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
@@ -10,13 +18,49 @@ pub enum Screen {
     Menu,
     /// The typing screen.
     Typing,
+    /// Progress statistics from the run history.
+    Stats,
+    /// Mistyped keys from the run history, drawn over a keyboard.
+    Heatmap,
+    /// Searchable list of the samples of the language highlighted in the menu.
+    Browser,
+}
+
+/// What the samples already finished in a timed run add to its totals. The
+/// keystroke, error and timeline counts simply carry on from sample to sample;
+/// these are the ones recomputed from the input of the current sample.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Banked {
+    pub samples: usize,
+    correct_chars: usize,
+    input_chars: usize,
+}
+
+/// The daily challenge on the typing screen.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Daily {
+    /// Days since the Unix epoch.
+    pub day: u64,
+    /// Whether this is the day's one scored attempt; later ones are practice.
+    pub scored: bool,
 }
 
 #[derive(Clone)]
 pub struct GameState {
     pub screen: Screen,
-    pub menu_index: usize, // highlighted language in the menu
+    pub menu_index: usize,         // highlighted language in the menu
+    pub stats_index: usize,        // stats filter: 0 = all languages, i + 1 = ALL[i]
+    pub browser_query: String, // sample browser search, matched against titles, tags and sources
+    pub browser_index: usize,  // highlighted entry among the browser matches
+    pub browser_sorted: bool,  // browser lists the easiest samples first
+    pub difficulty: Option<Level>, // only pick samples of this level at random; `None` for any
+    pub mode: Mode,            // how runs are played, picked in the menu
+    pub banked: Banked,        // samples already finished in the current run, when it streams
+    pub stop_on_error: bool,   // wrong keys count as errors but the cursor waits for the right one
+    pub blocked: bool,         // the last key was wrong and held back by `stop_on_error`
+    pub daily: Option<Daily>,  // set while the daily challenge is being played
     pub language: Language,
+    pub sample: Sample, // the sample being typed; its code and output are copied below
     pub current_code: String,
     pub current_code_chars: Vec<char>, // Pre-computed for O(1) access
     pub expected_output: String,       // Expected output when code is run
@@ -25,19 +69,59 @@ pub struct GameState {
     pub first_input_time: Option<Instant>,
     pub end_time: Option<Instant>,
     pub correct_chars: usize,
-    pub wpm: f32,
-    pub accuracy: f32,
+    pub keystrokes: usize,  // every key pressed this run, including backspaces
+    pub errors: usize,      // keystrokes that did not match the expected char, fixed or not
+    pub attempts: usize,    // keystrokes judged right or wrong (the denominator of raw accuracy)
+    pub typed_chars: usize, // chars entered this run, including ones later deleted
+    pub corrected_errors: usize, // mistakes that were backspaced away
+    pub uncorrected_errors: usize, // mistakes still in the input
+    pub mistakes: Vec<(char, char)>, // (expected, typed) for each mismatched keystroke
+    pub key_times: Vec<f32>, // ms after the first keystroke each char of user_input_chars was typed
+    pub run_ngrams: Vec<(String, f32, u32)>, // bigram/trigram latencies of the finished run
+    pub wpm_timeline: Vec<f32>, // WPM within each full second of the run, sampled by `tick`
+    timeline_chars: usize,  // typed_chars at the last timeline sample
+    pub wpm: f32,           // net: correctly typed chars only
+    pub raw_wpm: f32,       // every char entered, including mistakes and deleted ones
+    pub accuracy: f32,      // net: share of the current input that is correct
+    pub raw_accuracy: f32,  // share of all judged keystrokes that were correct
     pub game_over: bool,
     pub confirm_quit: bool,
-    current_sample_idx: usize, // Track current sample to avoid repeat
+    pub history: History,
+    pub library: Library,
+    pub sample_best: Option<PersonalBest>, // best on the current sample, from history
+    pub language_best: Option<PersonalBest>, // best on any sample of the current language
+    pub best_streak: Option<usize>,        // longest sudden-death streak in the current language
+    pub new_record: bool,                  // the finished run beat the previous sample best WPM
+    pub ghosts: Ghosts,
+    pub ghost: Option<Ghost>, // best recorded run on the current sample, raced while typing
+    pub replay: Option<ReplayStatus>, // set while a recording is being played back (nothing is saved)
+    pub clock: Option<Instant>, // overrides `Instant::now()`, so replays run on recording time
+    rng: SplitMix64,            // picks random samples; seeded by `--seed` to replay a session
+    current_sample_idx: usize,  // Track current sample to avoid repeat
 }
 
 impl GameState {
     pub fn new() -> Self {
+        Self::with_history(History::default())
+    }
+
+    /// Create a game whose finished runs are recorded into `history`.
+    pub fn with_history(history: History) -> Self {
         let mut state = GameState {
             screen: Screen::Menu,
             menu_index: 0,
+            stats_index: 0,
+            browser_query: String::new(),
+            browser_index: 0,
+            browser_sorted: false,
+            difficulty: None,
+            mode: Mode::default(),
+            stop_on_error: false,
+            blocked: false,
+            daily: None,
+            banked: Banked::default(),
             language: ALL[0],
+            sample: Sample::new("", "", ""),
             current_code: String::new(),
             current_code_chars: Vec::new(),
             expected_output: String::new(),
@@ -46,10 +130,34 @@ impl GameState {
             first_input_time: None,
             end_time: None,
             correct_chars: 0,
+            keystrokes: 0,
+            errors: 0,
+            attempts: 0,
+            typed_chars: 0,
+            corrected_errors: 0,
+            uncorrected_errors: 0,
+            mistakes: Vec::new(),
+            key_times: Vec::new(),
+            run_ngrams: Vec::new(),
+            wpm_timeline: Vec::new(),
+            timeline_chars: 0,
             wpm: 0.0,
+            raw_wpm: 0.0,
             accuracy: 0.0,
+            raw_accuracy: 0.0,
             game_over: false,
             confirm_quit: false,
+            history,
+            library: Library::default(),
+            sample_best: None,
+            language_best: None,
+            best_streak: None,
+            new_record: false,
+            ghosts: Ghosts::default(),
+            ghost: None,
+            replay: None,
+            clock: None,
+            rng: SplitMix64::new(rand::random()),
             current_sample_idx: usize::MAX, // sentinel: allow any first sample
         };
         state.load_random_sample();
@@ -57,65 +165,439 @@ impl GameState {
     }
 
     /// Samples for the currently selected language.
-    fn samples(&self) -> &'static [(&'static str, &'static str)] {
-        self.language.spec().samples
+    fn samples(&self) -> &[Sample] {
+        self.library.samples(self.language)
+    }
+
+    /// Play samples from `library` instead of the built-in ones.
+    pub fn set_library(&mut self, library: Library) {
+        self.library = library;
+        self.current_sample_idx = usize::MAX;
+        self.load_random_sample();
+    }
+
+    /// Pick samples from now on with an RNG seeded by `seed`, so the same
+    /// choices in the game lead to the same samples every time. The first
+    /// sample is picked again.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.rng = SplitMix64::new(seed);
+        self.current_sample_idx = usize::MAX;
+        self.load_random_sample();
     }
 
     // ── Menu navigation ──────────────────────────────────────────────────────
 
+    // The menu lists every language, then the daily challenge at `ALL.len()`.
+
     pub fn menu_up(&mut self) {
         self.menu_index = if self.menu_index == 0 {
-            ALL.len() - 1
+            ALL.len()
         } else {
             self.menu_index - 1
         };
     }
 
     pub fn menu_down(&mut self) {
-        self.menu_index = (self.menu_index + 1) % ALL.len();
+        self.menu_index = (self.menu_index + 1) % (ALL.len() + 1);
+    }
+
+    /// Step the difficulty filter through any → easy → medium → hard.
+    pub fn cycle_difficulty(&mut self) {
+        self.difficulty = match self.difficulty {
+            None => Some(LEVELS[0]),
+            Some(level) => LEVELS
+                .iter()
+                .position(|&l| l == level)
+                .and_then(|i| LEVELS.get(i + 1).copied()),
+        };
+    }
+
+    pub fn cycle_mode(&mut self) {
+        self.mode = self.mode.next();
+    }
+
+    /// How many of `language`'s samples the difficulty filter lets through.
+    pub fn playable_count(&self, language: Language) -> usize {
+        let samples = self.library.samples(language);
+        match self.difficulty {
+            Some(level) => samples.iter().filter(|s| is_level(s, level)).count(),
+            None => samples.len(),
+        }
     }
 
     /// Open the language menu, positioning the cursor on the current language.
     pub fn open_menu(&mut self) {
-        self.menu_index = ALL.iter().position(|&l| l == self.language).unwrap_or(0);
+        self.menu_index = match self.daily.take() {
+            Some(_) => ALL.len(),
+            None => ALL.iter().position(|&l| l == self.language).unwrap_or(0),
+        };
         self.screen = Screen::Menu;
     }
 
-    /// Confirm the menu selection: switch language and start a fresh sample.
+    /// Confirm the menu selection: switch language and start a fresh sample,
+    /// or start the daily challenge.
     pub fn select_menu_language(&mut self) {
-        self.language = ALL[self.menu_index];
+        let Some(&language) = ALL.get(self.menu_index) else {
+            self.start_daily();
+            return;
+        };
+        self.language = language;
         self.current_sample_idx = usize::MAX; // allow any first sample for the new language
         self.screen = Screen::Typing;
         self.load_random_sample();
     }
 
+    // ── Statistics ───────────────────────────────────────────────────────────
+
+    /// Open the statistics screen, filtered to the language highlighted in the
+    /// menu (all languages from the daily challenge).
+    pub fn open_stats(&mut self) {
+        self.stats_index = (self.menu_index + 1) % (ALL.len() + 1);
+        self.screen = Screen::Stats;
+    }
+
+    /// Open the key heatmap, filtered like [`Self::open_stats`].
+    pub fn open_heatmap(&mut self) {
+        self.stats_index = (self.menu_index + 1) % (ALL.len() + 1);
+        self.screen = Screen::Heatmap;
+    }
+
+    pub fn stats_prev(&mut self) {
+        self.stats_index = if self.stats_index == 0 {
+            ALL.len()
+        } else {
+            self.stats_index - 1
+        };
+    }
+
+    pub fn stats_next(&mut self) {
+        self.stats_index = (self.stats_index + 1) % (ALL.len() + 1);
+    }
+
+    /// The language the statistics and heatmap screens are filtered to, or `None` for all.
+    pub fn stats_language(&self) -> Option<Language> {
+        self.stats_index.checked_sub(1).map(|i| ALL[i])
+    }
+
+    /// Recorded runs matching the statistics filter, oldest first.
+    pub fn stats_runs(&self) -> Vec<&RunRecord> {
+        let language = self.stats_language();
+        self.history
+            .runs
+            .iter()
+            .filter(|r| match language {
+                Some(l) => r.language == l,
+                None => true,
+            })
+            .collect()
+    }
+
+    // ── Sample browser ───────────────────────────────────────────────────────
+
+    /// Browse the samples of the language highlighted in the menu.
+    pub fn open_browser(&mut self) {
+        self.browser_query.clear();
+        self.browser_index = 0;
+        self.screen = Screen::Browser;
+    }
+
+    /// The language highlighted in the menu; the current one from the daily challenge.
+    pub fn browser_language(&self) -> Language {
+        ALL.get(self.menu_index).copied().unwrap_or(self.language)
+    }
+
+    /// Indices of the browser language's samples matching every word of the
+    /// query (case-insensitively) in their title, tags, source or level.
+    pub fn browser_matches(&self) -> Vec<usize> {
+        let query = self.browser_query.to_lowercase();
+        let words: Vec<&str> = query.split_whitespace().collect();
+        let samples = self.library.samples(self.browser_language());
+        let mut matches: Vec<usize> = samples
+            .iter()
+            .enumerate()
+            .filter(|(_, sample)| {
+                let mut text = sample.title.to_lowercase();
+                for tag in sample.tags.iter() {
+                    text.push(' ');
+                    text.push_str(&tag.to_lowercase());
+                }
+                if let Some(source) = &sample.source {
+                    text.push(' ');
+                    text.push_str(&source.to_lowercase());
+                }
+                if let Some(difficulty) = sample.difficulty {
+                    text.push(' ');
+                    text.push_str(Level::of(difficulty).name());
+                }
+                words.iter().all(|word| text.contains(word))
+            })
+            .map(|(i, _)| i)
+            .collect();
+        if self.browser_sorted {
+            matches.sort_by_key(|&i| samples[i].difficulty);
+        }
+        matches
+    }
+
+    /// Toggle between library order and easiest first.
+    pub fn browser_toggle_sort(&mut self) {
+        self.browser_sorted = !self.browser_sorted;
+        self.browser_index = 0;
+    }
+
+    /// The sample highlighted in the browser, if any match the query.
+    pub fn browser_selection(&self) -> Option<&Sample> {
+        let idx = *self.browser_matches().get(self.browser_index)?;
+        self.library.samples(self.browser_language()).get(idx)
+    }
+
+    pub fn browser_up(&mut self) {
+        let len = self.browser_matches().len();
+        if len > 0 {
+            self.browser_index = (self.browser_index + len - 1) % len;
+        }
+    }
+
+    pub fn browser_down(&mut self) {
+        let len = self.browser_matches().len();
+        if len > 0 {
+            self.browser_index = (self.browser_index + 1) % len;
+        }
+    }
+
+    pub fn browser_type(&mut self, c: char) {
+        self.browser_query.push(c);
+        self.browser_index = 0;
+    }
+
+    pub fn browser_backspace(&mut self) {
+        self.browser_query.pop();
+        self.browser_index = 0;
+    }
+
+    /// Start typing the highlighted sample.
+    pub fn select_browser_sample(&mut self) {
+        if let Some(&idx) = self.browser_matches().get(self.browser_index) {
+            self.language = self.browser_language();
+            self.screen = Screen::Typing;
+            self.load_sample(idx);
+        }
+    }
+
     // ── Sample loading ─────────────────────────────────────────────────────────
 
     /// Load a specific sample within the current language and reset typing progress.
     fn load_sample(&mut self, idx: usize) {
-        let (code, output) = self.samples()[idx];
+        let sample = self.samples()[idx].clone();
         self.current_sample_idx = idx;
-        self.current_code = code.to_string();
-        self.current_code_chars = self.current_code.chars().collect();
-        self.expected_output = output.to_string();
+        self.daily = None;
+        self.set_sample(sample);
+    }
+
+    /// Start today's daily challenge. The first attempt of the day is scored
+    /// and recorded as a [`Mode::Daily`] run; any after it are practice.
+    pub fn start_daily(&mut self) {
+        let day = history::today();
+        let (language, idx) = daily_sample(day);
+        self.language = language;
+        self.screen = Screen::Typing;
+        // Not necessarily a sample of the loaded library
+        self.current_sample_idx = usize::MAX;
+        self.daily = Some(Daily {
+            day,
+            scored: self.history.daily_result(day).is_none(),
+        });
+        self.set_sample(Library::default().samples(language)[idx].clone());
+    }
+
+    /// How the current run is played and recorded: the menu's mode, or a
+    /// single sample for the daily challenge.
+    pub fn run_mode(&self) -> Mode {
+        match self.daily {
+            Some(Daily { day, scored: true }) => Mode::Daily(day),
+            Some(_) => Mode::Sample,
+            None => self.mode,
+        }
+    }
+
+    /// Start typing sample `idx` of `language`.
+    pub fn start_sample(&mut self, language: Language, idx: usize) {
+        self.language = language;
+        self.screen = Screen::Typing;
+        self.load_sample(idx);
+    }
+
+    /// Whether the current sample is one part of a longer source with more to come.
+    pub fn has_next_part(&self) -> bool {
+        !self.run_mode().streams()
+            && self
+                .library
+                .next_part(self.language, self.current_sample_idx)
+                .is_some()
+    }
+
+    /// Continue with the next part of the current source, once the run is over.
+    pub fn next_part(&mut self) {
+        if !self.game_over {
+            return;
+        }
+        if let Some(idx) = self
+            .library
+            .next_part(self.language, self.current_sample_idx)
+        {
+            self.load_sample(idx);
+        }
+    }
+
+    /// Show arbitrary code on the typing screen, e.g. the sample of a recording.
+    /// If it is one of the language's samples, that sample's details are used.
+    pub fn load_code(&mut self, language: Language, code: &str, output: &str) {
+        self.language = language;
+        self.current_sample_idx = self
+            .samples()
+            .iter()
+            .position(|s| s.code == code)
+            .unwrap_or(usize::MAX);
+        self.screen = Screen::Typing;
+        let sample = match self.samples().get(self.current_sample_idx) {
+            Some(sample) => sample.clone(),
+            None => {
+                let mut sample = Sample::from_code("Untitled", code);
+                sample.output = output.to_string().into();
+                sample
+            }
+        };
+        self.set_sample(sample);
+    }
+
+    fn set_sample(&mut self, sample: Sample) {
+        self.show_sample(sample);
+        self.refresh_bests();
         self.reset_progress();
     }
 
+    /// Put `sample` on the typing screen, leaving progress alone.
+    fn show_sample(&mut self, sample: Sample) {
+        self.current_code = sample.code.to_string();
+        self.current_code_chars = self.current_code.chars().collect();
+        self.expected_output = sample.output.to_string();
+        self.sample = sample;
+    }
+
+    /// Re-read the personal bests and ghost for the current sample and language.
+    fn refresh_bests(&mut self) {
+        let id = history::sample_id(&self.current_code);
+        self.sample_best = self.history.best_for_sample(&id);
+        self.language_best = self.history.best_for_language(self.language);
+        self.best_streak = self.history.best_streak(self.language);
+        self.ghost = self.ghosts.get(&id).map(Ghost::new);
+    }
+
+    /// Keep a finished run's recording as the ghost of its sample if it is the
+    /// fastest yet. The new ghost is raced from the next attempt on.
+    pub fn offer_ghost(&mut self, recording: &Recording) {
+        // Like history writes, a failed save must never interrupt play.
+        if let Ok(true) = self.ghosts.offer(recording) {
+            self.refresh_bests();
+        }
+    }
+
+    /// Where the ghost's cursor is, while a run is in progress against one.
+    pub fn ghost_position(&self) -> Option<usize> {
+        let ghost = self.ghost.as_ref()?;
+        if self.first_input_time.is_none()
+            || self.game_over
+            || self.replay.is_some()
+            || self.run_mode().streams()
+        {
+            return None;
+        }
+        Some(ghost.position(self.elapsed_ms() as u64))
+    }
+
     /// Pick a random sample in the current language, avoiding an immediate repeat.
     fn load_random_sample(&mut self) {
-        let len = self.samples().len();
-        let mut idx = rand::thread_rng().gen_range(0..len);
+        let idx = self.random_sample_idx();
+        self.load_sample(idx);
+    }
+
+    fn random_sample_idx(&mut self) -> usize {
+        let playable = self.playable();
+        let len = playable.len();
+        let mut idx = playable[self.rng.below(len)];
         if len > 1 {
             while idx == self.current_sample_idx {
-                idx = rand::thread_rng().gen_range(0..len);
+                idx = playable[self.rng.below(len)];
             }
         }
-        self.load_sample(idx);
+        idx
+    }
+
+    /// In a timed run, move on to another sample without stopping the clock.
+    fn stream_next_sample(&mut self) {
+        self.update_stats();
+        self.banked = Banked {
+            samples: self.banked.samples + 1,
+            correct_chars: self.correct_chars,
+            input_chars: self.banked.input_chars + self.user_input_chars.len(),
+        };
+        self.run_ngrams.extend(stats::ngram_latencies(
+            &self.current_code_chars,
+            &self.user_input_chars,
+            &self.key_times,
+        ));
+
+        let idx = self.random_sample_idx();
+        self.current_sample_idx = idx;
+        self.show_sample(self.samples()[idx].clone());
+        self.user_input.clear();
+        self.user_input_chars.clear();
+        self.key_times.clear();
+        self.update_stats();
+    }
+
+    /// Time left in a timed run; the whole limit until the first keystroke.
+    pub fn time_left(&self) -> Option<Duration> {
+        let limit = Duration::from_secs(self.run_mode().time_limit()?);
+        Some(limit.saturating_sub(Duration::from_secs_f32(self.elapsed_ms() / 1000.0)))
+    }
+
+    /// The input reached the end of the code: the run is over, unless its mode
+    /// streams samples (and, if timed, has time left).
+    fn end_of_sample(&mut self) {
+        let time_up = self.time_left().is_some_and(|left| left.is_zero());
+        if self.run_mode().streams() && !time_up {
+            self.stream_next_sample();
+        } else {
+            self.finish_game();
+        }
+    }
+
+    /// Characters typed in a row without a mistake, in a sudden-death run.
+    pub fn streak(&self) -> usize {
+        self.correct_chars
+    }
+
+    /// Indices of the current language's samples at the filtered difficulty, or
+    /// of all of them if none are.
+    fn playable(&self) -> Vec<usize> {
+        let samples = self.samples();
+        let filtered: Vec<usize> = match self.difficulty {
+            Some(level) => (0..samples.len())
+                .filter(|&i| is_level(&samples[i], level))
+                .collect(),
+            None => Vec::new(),
+        };
+        if filtered.is_empty() {
+            (0..samples.len()).collect()
+        } else {
+            filtered
+        }
     }
 
     /// Switch to a random code sample (only works before typing starts).
     pub fn random_sample(&mut self) {
-        if self.first_input_time.is_none() && self.samples().len() > 1 {
+        if self.first_input_time.is_none() && self.daily.is_none() && self.playable().len() > 1 {
             self.load_random_sample();
         }
     }
@@ -127,8 +609,10 @@ impl GameState {
 
         // Record the time of first input
         if self.first_input_time.is_none() {
-            self.first_input_time = Some(Instant::now());
+            self.first_input_time = Some(self.now());
         }
+        self.keystrokes += 1;
+        let typed_at = self.elapsed_ms();
 
         // Convert tab to the language's indent width to match the code samples.
         // For Enter, auto-indent by matching the next line's leading whitespace.
@@ -160,32 +644,50 @@ impl GameState {
             for ch in chars_to_add {
                 self.user_input.push(ch);
                 self.user_input_chars.push(ch);
+                self.key_times.push(typed_at);
+                self.typed_chars += 1;
                 if self.user_input_chars.len() >= self.current_code_chars.len() {
-                    if all_correct {
-                        self.correct_chars += 1;
-                    }
-                    self.finish_game();
+                    self.judge(all_correct);
+                    self.end_of_sample();
                     return;
                 }
             }
 
             // Count as 1 correct if all matched
-            if all_correct {
-                self.correct_chars += 1;
+            self.judge(all_correct);
+            if !all_correct && self.run_mode() == Mode::SuddenDeath {
+                self.finish_game();
+                return;
             }
         } else {
             // Normal input
             for ch in chars_to_add {
-                self.user_input.push(ch);
-                self.user_input_chars.push(ch);
-
-                let pos = self.user_input_chars.len() - 1;
-                if pos < self.current_code_chars.len() && ch == self.current_code_chars[pos] {
-                    self.correct_chars += 1;
+                let pos = self.user_input_chars.len();
+                let correct =
+                    pos < self.current_code_chars.len() && ch == self.current_code_chars[pos];
+                self.typed_chars += 1;
+                self.judge(correct);
+                self.blocked = !correct && self.stop_on_error;
+                if !self.blocked {
+                    self.user_input.push(ch);
+                    self.user_input_chars.push(ch);
+                    self.key_times.push(typed_at);
+                }
+                if !correct {
+                    if let Some(&expected) = self.current_code_chars.get(pos) {
+                        self.mistakes.push((expected, ch));
+                    }
+                    if self.run_mode() == Mode::SuddenDeath {
+                        self.finish_game();
+                        return;
+                    }
+                    if self.blocked {
+                        break;
+                    }
                 }
 
                 if self.user_input_chars.len() >= self.current_code_chars.len() {
-                    self.finish_game();
+                    self.end_of_sample();
                     return;
                 }
             }
@@ -194,6 +696,16 @@ impl GameState {
         self.update_stats();
     }
 
+    /// Count one judged keystroke towards the raw (never-forgotten) totals.
+    fn judge(&mut self, correct: bool) {
+        self.attempts += 1;
+        if correct {
+            self.correct_chars += 1;
+        } else {
+            self.errors += 1;
+        }
+    }
+
     /// Get the characters to add for auto-indent when Enter is pressed.
     /// Returns the newline character plus any leading whitespace from the next line.
     fn get_auto_indent_chars(&self) -> String {
@@ -224,46 +736,173 @@ impl GameState {
 
     pub fn handle_backspace(&mut self) {
         if !self.game_over && !self.user_input_chars.is_empty() {
+            self.keystrokes += 1;
             // Remove last character
             self.user_input.pop();
             self.user_input_chars.pop();
+            self.key_times.pop();
             // update_stats will recalculate correct_chars and accuracy
             self.update_stats();
         }
     }
 
+    /// The current time: the real clock, or the replay clock during playback.
+    pub fn now(&self) -> Instant {
+        self.clock.unwrap_or_else(Instant::now)
+    }
+
+    /// Milliseconds since the first keystroke of the run, up to its end.
+    fn elapsed_ms(&self) -> f32 {
+        let end = self.end_time.unwrap_or_else(|| self.now());
+        self.first_input_time
+            .map(|t| end.duration_since(t).as_secs_f32() * 1000.0)
+            .unwrap_or(0.0)
+    }
+
     pub fn update_stats(&mut self) {
-        let elapsed_secs = self
-            .first_input_time
-            .map(|t| t.elapsed().as_secs_f32())
-            .unwrap_or(0.0);
+        let elapsed_secs = self.elapsed_ms() / 1000.0;
 
         // Recalculate correct_chars to ensure consistency
-        self.correct_chars = self
-            .user_input_chars
-            .iter()
-            .zip(self.current_code_chars.iter())
-            .filter(|(a, b)| a == b)
-            .count();
+        self.correct_chars = self.banked.correct_chars
+            + self
+                .user_input_chars
+                .iter()
+                .zip(self.current_code_chars.iter())
+                .filter(|(a, b)| a == b)
+                .count();
+
+        let input_len = self.banked.input_chars + self.user_input_chars.len();
+        self.uncorrected_errors = input_len - self.correct_chars;
+        self.corrected_errors = self.errors.saturating_sub(self.uncorrected_errors);
 
-        let input_len = self.user_input_chars.len();
         if elapsed_secs > 0.0 && input_len > 0 {
-            let words_typed = input_len as f32 / 5.0;
-            self.wpm = (words_typed / elapsed_secs) * 60.0;
+            let minutes = elapsed_secs / 60.0;
+            self.wpm = self.correct_chars as f32 / 5.0 / minutes;
+            self.raw_wpm = self.typed_chars as f32 / 5.0 / minutes;
             // Use input length for accuracy to ensure it never exceeds 100%
             self.accuracy = (self.correct_chars as f32 / input_len as f32) * 100.0;
         }
+        if self.attempts > 0 {
+            let right = self.attempts.saturating_sub(self.errors);
+            self.raw_accuracy = right as f32 / self.attempts as f32 * 100.0;
+        }
+    }
+
+    /// Called on every UI tick: append a WPM sample for each second that has
+    /// fully elapsed since the last one, and end a timed run that ran out.
+    pub fn tick(&mut self) {
+        if self.game_over {
+            return;
+        }
+        let started = self.first_input_time.is_some();
+        if started && self.time_left().is_some_and(|left| left.is_zero()) {
+            self.finish_game();
+        } else {
+            self.sample_elapsed_seconds();
+        }
+    }
+
+    fn sample_elapsed_seconds(&mut self) {
+        let elapsed_secs = self.elapsed_ms() / 1000.0;
+        while elapsed_secs >= (self.wpm_timeline.len() + 1) as f32 {
+            self.sample_timeline(1.0);
+        }
+    }
+
+    /// Record the chars typed since the previous sample as a WPM over `secs`.
+    fn sample_timeline(&mut self, secs: f32) {
+        let chars = self.typed_chars - self.timeline_chars;
+        self.timeline_chars = self.typed_chars;
+        self.wpm_timeline.push(chars as f32 / 5.0 / secs * 60.0);
     }
 
     pub fn finish_game(&mut self) {
+        // A timed run ends when its time is up, even if noticed a tick late.
+        let now = self.now();
+        let limit = self.run_mode().time_limit().map(Duration::from_secs);
+        self.end_time = Some(match (self.first_input_time, limit) {
+            (Some(start), Some(limit)) => now.min(start + limit),
+            _ => now,
+        });
+        self.sample_elapsed_seconds();
+        // Keep the trailing partial second unless it is too short to mean anything.
+        let partial = self.elapsed_ms() / 1000.0 - self.wpm_timeline.len() as f32;
+        if partial >= 0.25 {
+            self.sample_timeline(partial);
+        }
         self.game_over = true;
-        self.end_time = Some(Instant::now());
         self.update_stats();
+        self.run_ngrams.extend(stats::ngram_latencies(
+            &self.current_code_chars,
+            &self.user_input_chars,
+            &self.key_times,
+        ));
+        // Replays show the recorded run again; they are not new runs.
+        if self.replay.is_none() {
+            self.record_run();
+        }
+    }
+
+    /// Append the just-finished run to the history.
+    fn record_run(&mut self) {
+        let duration_secs = match (self.first_input_time, self.end_time) {
+            (Some(start), Some(end)) => end.duration_since(start).as_secs_f32(),
+            _ => 0.0,
+        };
+        let mut confusions = BTreeMap::new();
+        for &pair in &self.mistakes {
+            *confusions.entry(pair).or_insert(0) += 1;
+        }
+        let mode = self.run_mode();
+        let record = RunRecord {
+            timestamp: history::unix_now(),
+            language: self.language,
+            sample_id: if !mode.streams() {
+                history::sample_id(&self.current_code)
+            } else {
+                String::new()
+            },
+            wpm: self.wpm,
+            accuracy: self.accuracy,
+            duration_secs,
+            keystrokes: self.keystrokes,
+            errors: self.errors,
+            raw_wpm: self.raw_wpm,
+            raw_accuracy: self.raw_accuracy,
+            corrected_errors: self.corrected_errors,
+            uncorrected_errors: self.uncorrected_errors,
+            confusions: confusions
+                .into_iter()
+                .map(|((expected, typed), n)| (expected, typed, n))
+                .collect(),
+            ngrams: self.run_ngrams.clone(),
+            consistency: stats::consistency(&self.wpm_timeline),
+            burst_wpm: stats::burst(&self.wpm_timeline),
+            mode,
+            streak: (mode == Mode::SuddenDeath).then_some(self.streak()),
+        };
+        self.new_record = match mode {
+            Mode::Sample | Mode::Daily(_) => {
+                self.sample_best.is_some_and(|best| self.wpm > best.wpm)
+            }
+            Mode::SuddenDeath => self.best_streak.is_some_and(|best| self.streak() > best),
+            Mode::Timed(_) => false,
+        };
+        // A failed write must never interrupt play; the run is still kept in memory.
+        let _ = self.history.append(record);
+        self.refresh_bests();
     }
 
-    /// Move to a new random sample in the current language (used after completion).
+    /// Move to a new random sample in the current language (used after
+    /// completion). After the daily challenge, its sample stays up for practice.
     pub fn reset(&mut self) {
-        self.load_random_sample();
+        match &mut self.daily {
+            Some(daily) => {
+                daily.scored = false;
+                self.reset_progress();
+            }
+            None => self.load_random_sample(),
+        }
     }
 
     /// Restart the current sample (keep same code, reset progress).
@@ -275,16 +914,45 @@ impl GameState {
     fn reset_progress(&mut self) {
         self.user_input.clear();
         self.user_input_chars.clear();
+        self.banked = Banked::default();
+        self.blocked = false;
         self.first_input_time = None;
         self.end_time = None;
         self.correct_chars = 0;
+        self.keystrokes = 0;
+        self.errors = 0;
+        self.attempts = 0;
+        self.typed_chars = 0;
+        self.corrected_errors = 0;
+        self.uncorrected_errors = 0;
+        self.mistakes.clear();
+        self.key_times.clear();
+        self.run_ngrams.clear();
+        self.wpm_timeline.clear();
+        self.timeline_chars = 0;
         self.wpm = 0.0;
+        self.raw_wpm = 0.0;
         self.accuracy = 0.0;
+        self.raw_accuracy = 0.0;
         self.game_over = false;
+        self.new_record = false;
         self.confirm_quit = false;
     }
 }
 
+/// The daily challenge of `day` (days since the Unix epoch): a language and
+/// the index of one of its built-in samples, picked by an RNG seeded from the
+/// day so that everyone gets the same one.
+pub fn daily_sample(day: u64) -> (Language, usize) {
+    let mut rng = SplitMix64::new(day);
+    let language = ALL[rng.below(ALL.len())];
+    (language, rng.below(language.spec().samples.len()))
+}
+
+fn is_level(sample: &Sample, level: Level) -> bool {
+    sample.difficulty.map(Level::of) == Some(level)
+}
+
 impl Default for GameState {
     fn default() -> Self {
         Self::new()
@@ -294,6 +962,7 @@ impl Default for GameState {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crossterm::event::KeyCode;
 
     #[test]
     fn starts_in_menu_with_a_loaded_sample() {
@@ -314,6 +983,88 @@ mod tests {
         assert!(!gs.current_code.is_empty());
     }
 
+    #[test]
+    fn the_browser_filters_samples_and_starts_the_chosen_one() {
+        let mut gs = GameState::new();
+        gs.menu_index = ALL.iter().position(|&l| l == Language::Go).unwrap();
+        gs.open_browser();
+        assert_eq!(gs.screen, Screen::Browser);
+        let all = gs.browser_matches();
+        assert_eq!(all.len(), Language::Go.spec().samples.len());
+
+        let title = Language::Go.spec().samples[3].title.to_uppercase();
+        for c in title.chars() {
+            gs.browser_type(c);
+        }
+        let matches = gs.browser_matches();
+        assert!(matches.contains(&3));
+        assert!(matches.len() < all.len());
+        while gs.browser_matches()[gs.browser_index] != 3 {
+            gs.browser_down();
+        }
+        gs.select_browser_sample();
+        assert_eq!(gs.screen, Screen::Typing);
+        assert_eq!(gs.language, Language::Go);
+        assert_eq!(gs.sample.code, Language::Go.spec().samples[3].code);
+
+        gs.open_browser();
+        gs.browser_type('\u{1F47B}');
+        assert!(gs.browser_matches().is_empty());
+        assert!(gs.browser_selection().is_none());
+        gs.select_browser_sample(); // nothing to start
+        assert_eq!(gs.screen, Screen::Browser);
+    }
+
+    #[test]
+    fn the_difficulty_filter_limits_random_samples() {
+        let mut gs = GameState::new();
+        gs.cycle_difficulty();
+        gs.cycle_difficulty();
+        gs.cycle_difficulty();
+        assert_eq!(gs.difficulty, Some(Level::Hard));
+        let hard = gs.playable_count(Language::Rust);
+        assert!(0 < hard && hard < Language::Rust.spec().samples.len());
+        for _ in 0..20 {
+            gs.select_menu_language();
+            assert_eq!(gs.sample.difficulty.map(Level::of), Some(Level::Hard));
+        }
+        gs.cycle_difficulty();
+        assert_eq!(gs.difficulty, None);
+
+        gs.open_browser();
+        gs.browser_toggle_sort();
+        let difficulties: Vec<_> = gs
+            .browser_matches()
+            .iter()
+            .map(|&i| gs.library.samples(Language::Rust)[i].difficulty)
+            .collect();
+        assert!(difficulties.windows(2).all(|w| w[0] <= w[1]));
+        for c in "hard".chars() {
+            gs.browser_type(c);
+        }
+        assert_eq!(gs.browser_matches().len(), hard);
+    }
+
+    #[test]
+    fn finished_parts_continue_with_the_next_one() {
+        let code: String = (1..=12).map(|i| format!("x{i} = {i}\n")).collect();
+        let mut library = Library::default();
+        library.set_window(10);
+        library.add_custom(Language::Python, Sample::from_code("long.py", code));
+        let mut gs = GameState::new();
+        gs.set_library(library);
+        gs.start_sample(Language::Python, 0);
+        assert!(gs.has_next_part());
+
+        gs.next_part(); // not finished yet
+        assert!(gs.current_code.starts_with("x1 ="));
+        type_correctly(&mut gs);
+        gs.next_part();
+        assert_eq!(gs.current_code, "x11 = 11\nx12 = 12");
+        assert!(!gs.game_over);
+        assert!(!gs.has_next_part());
+    }
+
     #[test]
     fn tab_expands_to_the_language_indent_width() {
         let mut gs = GameState::new();
@@ -324,12 +1075,318 @@ mod tests {
         assert!(gs.user_input_chars.iter().all(|&c| c == ' '));
     }
 
+    /// Type the rest of the current sample without mistakes.
+    fn type_correctly(gs: &mut GameState) {
+        while !gs.game_over {
+            let c = gs.current_code_chars[gs.user_input_chars.len()];
+            gs.handle_input(c);
+        }
+    }
+
+    #[test]
+    fn finishing_a_run_records_it_in_history() {
+        let mut gs = GameState::new();
+        gs.select_menu_language();
+        let wrong = if gs.current_code_chars[0] == 'x' {
+            'y'
+        } else {
+            'x'
+        };
+        gs.handle_input(wrong);
+        gs.handle_backspace();
+        type_correctly(&mut gs);
+
+        assert_eq!(gs.history.runs.len(), 1);
+        let run = &gs.history.runs[0];
+        assert_eq!(run.language, gs.language);
+        assert_eq!(run.sample_id, history::sample_id(&gs.current_code));
+        assert_eq!(run.errors, 1);
+        assert_eq!(run.confusions, vec![(gs.current_code_chars[0], wrong, 1)]);
+        assert_eq!(gs.key_times.len(), gs.user_input_chars.len());
+        assert!(!run.ngrams.is_empty());
+        assert!(run.keystrokes > 2);
+    }
+
+    #[test]
+    fn backspaced_mistakes_still_count_against_raw_accuracy() {
+        let mut gs = GameState::new();
+        gs.select_menu_language();
+        let first = gs.current_code_chars[0];
+        let wrong = if first == 'x' { 'y' } else { 'x' };
+
+        // One mistake fixed, then one mistake left in place.
+        gs.handle_input(wrong);
+        gs.handle_backspace();
+        gs.handle_input(first);
+        gs.handle_input(wrong);
+        assert_eq!(gs.errors, 2);
+        assert_eq!(gs.corrected_errors, 1);
+        assert_eq!(gs.uncorrected_errors, 1);
+        assert_eq!(gs.accuracy, 50.0); // net: 1 of the 2 chars on screen is right
+        assert!((gs.raw_accuracy - 100.0 / 3.0).abs() < 0.01); // raw: 1 of 3 keystrokes
+        assert!(gs.raw_wpm > gs.wpm);
+    }
+
+    #[test]
+    fn tick_samples_wpm_once_per_elapsed_second() {
+        let mut gs = GameState::new();
+        gs.select_menu_language();
+        gs.tick();
+        assert!(gs.wpm_timeline.is_empty()); // not started yet
+
+        for &c in &gs.current_code_chars.clone()[..5] {
+            gs.handle_input(c);
+        }
+        // Pretend the run started 2.5 seconds ago.
+        gs.first_input_time = Some(Instant::now() - std::time::Duration::from_millis(2500));
+        gs.tick();
+        assert_eq!(gs.wpm_timeline, vec![60.0, 0.0]); // 5 chars in the first second
+        gs.tick();
+        assert_eq!(gs.wpm_timeline.len(), 2);
+    }
+
+    #[test]
+    fn replayed_runs_use_the_replay_clock_and_are_not_recorded() {
+        let mut gs = GameState::new();
+        gs.load_code(Language::Go, "x := 1", "");
+        assert_eq!(gs.screen, Screen::Typing);
+        assert_eq!(gs.language, Language::Go);
+
+        let start = Instant::now();
+        gs.replay = Some(ReplayStatus {
+            speed: 1,
+            paused: false,
+        });
+        gs.clock = Some(start);
+        gs.handle_input('x');
+        gs.clock = Some(start + std::time::Duration::from_secs(6));
+        type_correctly(&mut gs);
+        assert!(gs.game_over);
+        assert!(gs.history.runs.is_empty());
+        // 6 chars in 6 seconds of recording time = 12 WPM, however long the test took.
+        assert_eq!(gs.wpm, 12.0);
+    }
+
+    #[test]
+    fn timed_runs_stream_samples_until_the_time_is_up() {
+        let mut gs = GameState::new();
+        gs.cycle_mode();
+        gs.cycle_mode();
+        assert_eq!(gs.mode, Mode::Timed(30));
+        gs.select_menu_language();
+        let start = Instant::now();
+        gs.clock = Some(start);
+
+        let first = gs.current_code.clone();
+        while gs.banked.samples == 0 {
+            let c = gs.current_code_chars[gs.user_input_chars.len()];
+            gs.handle_input(c);
+        }
+        assert!(!gs.game_over);
+        assert_ne!(gs.current_code, first);
+        assert!(gs.user_input_chars.is_empty());
+        assert_eq!(gs.correct_chars, first.chars().count());
+        assert_eq!(gs.time_left(), Some(Duration::from_secs(30)));
+
+        gs.clock = Some(start + Duration::from_secs(31));
+        gs.tick();
+        assert!(gs.game_over);
+        // Counted over the 30 seconds, not up to the late tick
+        assert_eq!(gs.wpm, first.chars().count() as f32 / 5.0 / 0.5);
+        let run = gs.history.runs.last().unwrap();
+        assert_eq!(run.mode, Mode::Timed(30));
+        assert_eq!(run.sample_id, "");
+        assert_eq!(run.duration_secs, 30.0);
+        assert_eq!(gs.wpm_timeline.len(), 30);
+    }
+
+    #[test]
+    fn sudden_death_ends_at_the_first_mistake_and_keeps_the_best_streak() {
+        let mut gs = GameState::new();
+        gs.mode = Mode::SuddenDeath;
+        gs.select_menu_language();
+        let first = gs.current_code_chars.len();
+        while gs.banked.samples == 0 {
+            let c = gs.current_code_chars[gs.user_input_chars.len()];
+            gs.handle_input(c);
+        }
+        assert!(!gs.game_over);
+        // No sample contains it, so it is wrong wherever it is typed
+        let wrong = '§';
+        gs.handle_input(wrong);
+        assert!(gs.game_over);
+        assert_eq!(gs.streak(), first);
+        assert_eq!(gs.history.runs.last().unwrap().streak, Some(first));
+        assert_eq!(gs.best_streak, Some(first));
+
+        gs.reset();
+        gs.handle_input(wrong);
+        assert!(gs.game_over);
+        assert!(!gs.new_record);
+        assert_eq!(gs.best_streak, Some(first));
+    }
+
+    #[test]
+    fn the_daily_challenge_is_the_same_sample_all_day_and_scored_once() {
+        // Pinned: changing how the daily is picked changes it for everyone
+        assert_eq!(daily_sample(20_000), (Language::Cpp, 4));
+        let days: Vec<_> = (20_000..20_010).map(daily_sample).collect();
+        assert!(days.iter().any(|&d| d != days[0]));
+
+        let mut gs = GameState::new();
+        gs.mode = Mode::SuddenDeath;
+        gs.menu_index = ALL.len();
+        gs.select_menu_language();
+        let day = history::today();
+        let (language, _) = daily_sample(day);
+        assert_eq!(gs.language, language);
+        assert_eq!(gs.run_mode(), Mode::Daily(day));
+        let code = gs.current_code.clone();
+
+        // A mistake does not end it, whatever the menu's mode
+        gs.handle_input('§');
+        assert!(!gs.game_over);
+        gs.handle_backspace();
+        type_correctly(&mut gs);
+        assert!(gs.game_over);
+        let run = gs.history.daily_result(day).unwrap();
+        assert_eq!(run.sample_id, history::sample_id(&code));
+        assert_eq!(run.streak, None);
+
+        // Later attempts are practice, on the same sample
+        gs.reset();
+        assert_eq!(gs.current_code, code);
+        assert_eq!(gs.run_mode(), Mode::Sample);
+        gs.open_menu();
+        assert_eq!(gs.menu_index, ALL.len());
+        gs.select_menu_language();
+        assert_eq!(gs.daily, Some(Daily { day, scored: false }));
+        type_correctly(&mut gs);
+        assert_eq!(gs.history.runs.last().unwrap().mode, Mode::Sample);
+        assert_eq!(gs.history.runs.len(), 2);
+    }
+
+    #[test]
+    fn stop_on_error_holds_the_cursor_until_the_right_key() {
+        let mut gs = GameState::new();
+        gs.stop_on_error = true;
+        gs.load_code(Language::Go, "x := 1", "");
+        gs.handle_input('y');
+        assert!(gs.blocked);
+        assert!(gs.user_input_chars.is_empty());
+        assert_eq!(gs.errors, 1);
+
+        gs.handle_input('x');
+        assert!(!gs.blocked);
+        assert_eq!(gs.user_input, "x");
+        type_correctly(&mut gs);
+        assert_eq!(gs.mistakes, [('x', 'y')]);
+        assert_eq!(gs.accuracy, 100.0);
+        assert!(gs.raw_accuracy < 100.0);
+        assert_eq!((gs.corrected_errors, gs.uncorrected_errors), (1, 0));
+    }
+
+    #[test]
+    fn the_ghost_races_from_the_first_keystroke() {
+        let mut gs = GameState::new();
+        gs.load_code(Language::Go, "x := 1", "");
+        assert!(gs.ghost.is_none());
+        gs.offer_ghost(&Recording {
+            timestamp: 0,
+            language: Language::Go,
+            code: "x := 1".to_string(),
+            expected_output: String::new(),
+            wpm: 60.0,
+            accuracy: 100.0,
+            events: "x := 1"
+                .chars()
+                .enumerate()
+                .map(|(i, c)| (i as u64 * 1000, KeyCode::Char(c)))
+                .collect(),
+            stop_on_error: false,
+        });
+        assert_eq!(gs.ghost_position(), None); // not started yet
+
+        let start = Instant::now();
+        gs.clock = Some(start);
+        gs.handle_input('x');
+        gs.clock = Some(start + std::time::Duration::from_millis(2500));
+        assert_eq!(gs.ghost_position(), Some(3));
+    }
+
+    #[test]
+    fn beating_the_sample_best_flags_a_new_record() {
+        let mut gs = GameState::new();
+        gs.select_menu_language();
+        let slow = RunRecord {
+            timestamp: 0,
+            language: gs.language,
+            sample_id: history::sample_id(&gs.current_code),
+            wpm: 0.01,
+            accuracy: 50.0,
+            duration_secs: 600.0,
+            keystrokes: 10,
+            errors: 5,
+            ..Default::default()
+        };
+        gs.history.append(slow).unwrap();
+        gs.restart_current();
+        gs.refresh_bests();
+        assert_eq!(gs.sample_best.map(|b| b.wpm), Some(0.01));
+
+        type_correctly(&mut gs);
+        assert!(gs.new_record);
+        assert_eq!(gs.sample_best.map(|b| b.accuracy), Some(100.0));
+        assert_eq!(gs.language_best, gs.sample_best);
+    }
+
+    #[test]
+    fn stats_filter_cycles_through_all_and_each_language() {
+        let mut gs = GameState::new();
+        gs.menu_index = 1;
+        gs.open_stats();
+        assert_eq!(gs.screen, Screen::Stats);
+        assert_eq!(gs.stats_language(), Some(ALL[1]));
+        gs.stats_prev();
+        assert_eq!(gs.stats_language(), Some(ALL[0]));
+        gs.stats_prev();
+        assert_eq!(gs.stats_language(), None);
+        gs.stats_prev();
+        assert_eq!(gs.stats_language(), ALL.last().copied());
+        gs.stats_next();
+        assert_eq!(gs.stats_language(), None);
+    }
+
+    #[test]
+    fn the_same_seed_gives_the_same_samples() {
+        let play = |seed: u64| {
+            let mut gs = GameState::new();
+            gs.set_seed(seed);
+            let mut codes = vec![gs.current_code.clone()];
+            gs.menu_index = ALL.iter().position(|&l| l == Language::Go).unwrap();
+            gs.select_menu_language();
+            for _ in 0..5 {
+                codes.push(gs.current_code.clone());
+                gs.reset();
+            }
+            codes
+        };
+        assert_eq!(play(7), play(7));
+        assert_ne!(play(7), play(8));
+
+        // Pinned: a seed must pick the same samples in every build
+        let mut gs = GameState::new();
+        gs.set_seed(7);
+        assert_eq!(gs.current_sample_idx, 38);
+    }
+
     #[test]
     fn menu_navigation_wraps() {
         let mut gs = GameState::new();
         gs.menu_index = 0;
         gs.menu_up();
-        assert_eq!(gs.menu_index, ALL.len() - 1);
+        // The daily challenge comes after the languages
+        assert_eq!(gs.menu_index, ALL.len());
         gs.menu_down();
         assert_eq!(gs.menu_index, 0);
     }
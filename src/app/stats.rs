@@ -0,0 +1,295 @@
+//! Aggregations over recorded runs, shared by the completion bar and the
+//! statistics screen.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use super::history::RunRecord;
+
+/// Performance rating awarded for a finished run.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Rating {
+    Legendary,
+    Excellent,
+    Good,
+    Complete,
+}
+
+impl Rating {
+    /// All ratings, best first.
+    pub const ALL: [Rating; 4] = [
+        Rating::Legendary,
+        Rating::Excellent,
+        Rating::Good,
+        Rating::Complete,
+    ];
+
+    pub fn of(wpm: f32, accuracy: f32) -> Self {
+        if wpm >= 80.0 && accuracy >= 98.0 {
+            Rating::Legendary
+        } else if wpm >= 60.0 && accuracy >= 95.0 {
+            Rating::Excellent
+        } else if wpm >= 40.0 && accuracy >= 85.0 {
+            Rating::Good
+        } else {
+            Rating::Complete
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Rating::Legendary => "🏆 LEGENDARY",
+            Rating::Excellent => "⭐ EXCELLENT",
+            Rating::Good => "✓ GOOD",
+            Rating::Complete => "→ COMPLETE",
+        }
+    }
+}
+
+/// Totals and averages over a set of runs.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Summary {
+    pub runs: usize,
+    /// Distinct samples completed at least once.
+    pub samples: usize,
+    pub total_secs: f32,
+    pub avg_wpm: f32,
+    pub avg_accuracy: f32,
+    pub best_wpm: f32,
+    /// Run counts per rating, in `Rating::ALL` order.
+    pub ratings: [usize; 4],
+}
+
+impl Summary {
+    pub fn of(runs: &[&RunRecord]) -> Self {
+        if runs.is_empty() {
+            return Summary::default();
+        }
+        let mut ratings = [0; 4];
+        for run in runs {
+            let rating = Rating::of(run.wpm, run.accuracy);
+            ratings[Rating::ALL.iter().position(|&r| r == rating).unwrap()] += 1;
+        }
+        let n = runs.len() as f32;
+        Summary {
+            runs: runs.len(),
+            // Runs over several samples have no sample id
+            samples: runs
+                .iter()
+                .map(|r| r.sample_id.as_str())
+                .filter(|id| !id.is_empty())
+                .collect::<HashSet<_>>()
+                .len(),
+            total_secs: runs.iter().map(|r| r.duration_secs).sum(),
+            avg_wpm: runs.iter().map(|r| r.wpm).sum::<f32>() / n,
+            avg_accuracy: runs.iter().map(|r| r.accuracy).sum::<f32>() / n,
+            best_wpm: runs.iter().map(|r| r.wpm).fold(0.0, f32::max),
+            ratings,
+        }
+    }
+}
+
+/// Mistyped characters aggregated over runs.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct KeyMisses {
+    /// Misses per expected character.
+    pub by_char: HashMap<char, u32>,
+    /// `(expected, typed, count)`, most frequent first.
+    pub confusions: Vec<(char, char, u32)>,
+}
+
+impl KeyMisses {
+    pub fn of(runs: &[&RunRecord]) -> Self {
+        let mut by_char = HashMap::new();
+        let mut pairs: HashMap<(char, char), u32> = HashMap::new();
+        for run in runs {
+            for &(expected, typed, n) in &run.confusions {
+                *by_char.entry(expected).or_insert(0) += n;
+                *pairs.entry((expected, typed)).or_insert(0) += n;
+            }
+        }
+        let mut confusions: Vec<(char, char, u32)> =
+            pairs.into_iter().map(|((e, t), n)| (e, t, n)).collect();
+        confusions.sort_by(|a, b| b.2.cmp(&a.2).then((a.0, a.1).cmp(&(b.0, b.1))));
+        KeyMisses {
+            by_char,
+            confusions,
+        }
+    }
+
+    /// Misses for a physical key, i.e. both its plain and shifted character.
+    pub fn for_key(&self, plain: char, shifted: Option<char>) -> u32 {
+        let count = |c| self.by_char.get(&c).copied().unwrap_or(0);
+        count(plain) + shifted.map_or(0, count)
+    }
+
+    /// Characters ordered by miss count, most missed first.
+    pub fn most_missed(&self) -> Vec<(char, u32)> {
+        let mut chars: Vec<(char, u32)> = self.by_char.iter().map(|(&c, &n)| (c, n)).collect();
+        chars.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        chars
+    }
+}
+
+/// Time taken to type each bigram and trigram of a run, as
+/// `(sequence, total_ms, count)`. The latency of `abc` is the time from typing `a`
+/// to typing `c`. Sequences containing whitespace (where auto-indent and line
+/// breaks dominate) or any mistyped character are skipped.
+pub fn ngram_latencies(
+    expected: &[char],
+    typed: &[char],
+    times: &[f32],
+) -> Vec<(String, f32, u32)> {
+    let len = typed.len().min(expected.len()).min(times.len());
+    let clean = |i: usize| typed[i] == expected[i] && !typed[i].is_whitespace();
+    let mut totals: BTreeMap<String, (f32, u32)> = BTreeMap::new();
+    for n in 2..=3 {
+        for start in 0..len.saturating_sub(n - 1) {
+            let end = start + n - 1;
+            if (start..=end).all(clean) {
+                let seq: String = typed[start..=end].iter().collect();
+                let entry = totals.entry(seq).or_insert((0.0, 0));
+                entry.0 += times[end] - times[start];
+                entry.1 += 1;
+            }
+        }
+    }
+    totals
+        .into_iter()
+        .map(|(seq, (total, count))| (seq, total, count))
+        .collect()
+}
+
+/// Average latency per sequence over `ngrams` (in the format stored per run),
+/// slowest first, ignoring sequences seen fewer than `min_count` times.
+pub fn slowest_ngrams<'a>(
+    ngrams: impl IntoIterator<Item = &'a (String, f32, u32)>,
+    min_count: u32,
+) -> Vec<(String, f32)> {
+    let mut totals: HashMap<&str, (f32, u32)> = HashMap::new();
+    for (seq, total, count) in ngrams {
+        let entry = totals.entry(seq).or_insert((0.0, 0));
+        entry.0 += total;
+        entry.1 += count;
+    }
+    let mut slowest: Vec<(String, f32)> = totals
+        .into_iter()
+        .filter(|(_, (_, count))| *count >= min_count)
+        .map(|(seq, (total, count))| (seq.to_string(), total / count as f32))
+        .collect();
+    slowest.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    slowest
+}
+
+/// How steady a per-second WPM timeline is: 100 minus the coefficient of
+/// variation (as a percentage), floored at 0. A flat line scores 100.
+pub fn consistency(timeline: &[f32]) -> f32 {
+    if timeline.is_empty() {
+        return 0.0;
+    }
+    let n = timeline.len() as f32;
+    let mean = timeline.iter().sum::<f32>() / n;
+    if mean <= 0.0 {
+        return 0.0;
+    }
+    let variance = timeline.iter().map(|w| (w - mean).powi(2)).sum::<f32>() / n;
+    (100.0 - variance.sqrt() / mean * 100.0).max(0.0)
+}
+
+/// Peak one-second WPM.
+pub fn burst(timeline: &[f32]) -> f32 {
+    timeline.iter().copied().fold(0.0, f32::max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::language::Language;
+
+    fn run(sample: &str, wpm: f32, accuracy: f32) -> RunRecord {
+        RunRecord {
+            timestamp: 0,
+            language: Language::Rust,
+            sample_id: sample.to_string(),
+            wpm,
+            accuracy,
+            duration_secs: 30.0,
+            keystrokes: 100,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn rating_thresholds() {
+        assert_eq!(Rating::of(80.0, 98.0), Rating::Legendary);
+        assert_eq!(Rating::of(90.0, 97.0), Rating::Excellent);
+        assert_eq!(Rating::of(40.0, 85.0), Rating::Good);
+        assert_eq!(Rating::of(100.0, 50.0), Rating::Complete);
+    }
+
+    #[test]
+    fn summary_totals_and_distribution() {
+        let runs = [
+            run("a", 85.0, 99.0),
+            run("a", 45.0, 90.0),
+            run("b", 10.0, 60.0),
+        ];
+        let refs: Vec<&RunRecord> = runs.iter().collect();
+        let summary = Summary::of(&refs);
+        assert_eq!(summary.runs, 3);
+        assert_eq!(summary.samples, 2);
+        assert_eq!(summary.total_secs, 90.0);
+        assert_eq!(summary.avg_wpm, 140.0 / 3.0);
+        assert_eq!(summary.best_wpm, 85.0);
+        assert_eq!(summary.ratings, [1, 0, 1, 1]);
+        assert_eq!(Summary::of(&[]), Summary::default());
+
+        let timed = run("", 60.0, 95.0);
+        let refs: Vec<&RunRecord> = runs.iter().chain([&timed]).collect();
+        assert_eq!(Summary::of(&refs).samples, 2);
+    }
+
+    #[test]
+    fn ngram_latency_skips_whitespace_and_mistakes() {
+        let expected: Vec<char> = "a::b c".chars().collect();
+        let typed: Vec<char> = "a::x c".chars().collect();
+        let times = [0.0, 100.0, 400.0, 500.0, 600.0, 700.0];
+        let ngrams = ngram_latencies(&expected, &typed, &times);
+        assert_eq!(
+            ngrams,
+            vec![
+                ("::".to_string(), 300.0, 1),
+                ("a:".to_string(), 100.0, 1),
+                ("a::".to_string(), 400.0, 1),
+            ]
+        );
+
+        let other = vec![("::".to_string(), 100.0, 1), ("ab".to_string(), 50.0, 2)];
+        let slowest = slowest_ngrams(ngrams.iter().chain(&other), 2);
+        assert_eq!(
+            slowest,
+            vec![("::".to_string(), 200.0), ("ab".to_string(), 25.0)]
+        );
+    }
+
+    #[test]
+    fn consistency_is_100_for_a_steady_typist() {
+        assert_eq!(consistency(&[60.0, 60.0, 60.0]), 100.0);
+        assert_eq!(consistency(&[]), 0.0);
+        // mean 60, standard deviation 30 => CV 50%
+        assert_eq!(consistency(&[30.0, 90.0]), 50.0);
+        assert_eq!(burst(&[30.0, 90.0]), 90.0);
+    }
+
+    #[test]
+    fn key_misses_merge_runs_and_shifted_keys() {
+        let mut a = run("a", 50.0, 90.0);
+        a.confusions = vec![('|', '\\', 2), ('>', '.', 1)];
+        let mut b = run("b", 50.0, 90.0);
+        b.confusions = vec![('|', '\\', 1), ('\\', '|', 1)];
+        let misses = KeyMisses::of(&[&a, &b]);
+        assert_eq!(misses.confusions[0], ('|', '\\', 3));
+        assert_eq!(misses.for_key('\\', Some('|')), 4);
+        assert_eq!(misses.for_key('.', Some('>')), 1);
+        assert_eq!(misses.most_missed()[0], ('|', 3));
+    }
+}
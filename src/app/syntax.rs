@@ -282,12 +282,10 @@ fn fill(result: &mut [TokenType], start: usize, end: usize, t: TokenType) {
 
 /// True if `chars` starting at `i` matches the (ASCII) pattern `pat`.
 fn matches_at(chars: &[char], i: usize, pat: &str) -> bool {
-    let mut k = i;
-    for pc in pat.chars() {
-        if k >= chars.len() || chars[k] != pc {
+    for (offset, pc) in pat.chars().enumerate() {
+        if chars.get(i + offset) != Some(&pc) {
             return false;
         }
-        k += 1;
     }
     true
 }
@@ -371,7 +369,8 @@ mod tests {
     fn one_token_per_char_for_all_samples() {
         for &lang in ALL {
             let spec = lang.spec();
-            for (code, _) in spec.samples {
+            for sample in spec.samples {
+                let code = &sample.code;
                 let tokens = highlight(code, spec);
                 assert_eq!(
                     tokens.len(),
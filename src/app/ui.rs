@@ -3,13 +3,19 @@ use ratatui::{
     style::{Color, Modifier, Style, Stylize},
     symbols,
     text::{Line, Span},
-    widgets::{Block, BorderType, Borders, Clear, LineGauge, Padding, Paragraph, Wrap},
+    widgets::{
+        Axis, Bar, BarChart, BarGroup, Block, BorderType, Borders, Chart, Clear, Dataset,
+        GraphType, LineGauge, Padding, Paragraph, Wrap,
+    },
     Frame,
 };
-use std::time::Instant;
 
+use super::difficulty::Level;
+use super::history::{self, PersonalBest, RunRecord};
 use super::language::ALL;
-use super::state::Screen;
+use super::mode::Mode;
+use super::state::{self, Screen};
+use super::stats::{self, KeyMisses, Rating, Summary};
 use super::syntax;
 use super::GameState;
 
@@ -28,11 +34,15 @@ const COLOR_GRAY: Color = Color::Rgb(100, 110, 150); // Muted but visible
 const COLOR_DARK: Color = Color::Rgb(26, 27, 38); // Dark bg
 const COLOR_SURFACE: Color = Color::Rgb(36, 40, 59); // Surface
 const COLOR_CURSOR_BG: Color = Color::Rgb(255, 220, 100); // Bright cursor background
+const COLOR_GHOST: Color = Color::Rgb(75, 60, 120); // Ghost cursor background
 
 pub fn draw(f: &mut Frame, game_state: &GameState) {
     match game_state.screen {
         Screen::Menu => draw_menu(f, game_state),
         Screen::Typing => draw_typing(f, game_state),
+        Screen::Stats => draw_stats(f, game_state),
+        Screen::Heatmap => draw_heatmap(f, game_state),
+        Screen::Browser => draw_browser(f, game_state),
     }
 
     if game_state.confirm_quit {
@@ -60,11 +70,35 @@ fn draw_menu(f: &mut Frame, game_state: &GameState) {
     let inner = block.inner(area);
     f.render_widget(block, area);
 
+    let (difficulty, difficulty_color) = match game_state.difficulty {
+        Some(level) => (level.name(), level_color(level)),
+        None => ("any", COLOR_WHITE),
+    };
     let mut lines = vec![
         Line::from(Span::styled(
             "Select a language to practice",
             Style::default().fg(COLOR_WHITE).bold(),
         )),
+        Line::from(vec![
+            Span::styled("Difficulty: ", Style::default().fg(COLOR_GRAY)),
+            Span::styled(difficulty, Style::default().fg(difficulty_color).bold()),
+            Span::styled("   Mode: ", Style::default().fg(COLOR_GRAY)),
+            Span::styled(
+                game_state.mode.name(),
+                Style::default().fg(COLOR_WHITE).bold(),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled("Stop on errors: ", Style::default().fg(COLOR_GRAY)),
+            Span::styled(
+                if game_state.stop_on_error {
+                    "on"
+                } else {
+                    "off"
+                },
+                Style::default().fg(COLOR_WHITE).bold(),
+            ),
+        ]),
         Line::from(""),
     ];
 
@@ -80,18 +114,72 @@ fn draw_menu(f: &mut Frame, game_state: &GameState) {
             Span::styled(format!("{} ", lang.spec().emoji), name_style),
             Span::styled(format!("{:<12}", lang.name()), name_style),
             Span::styled(
-                format!("{} snippets", lang.spec().samples.len()),
+                format!(
+                    "{} {}{}snippets",
+                    game_state.playable_count(lang),
+                    match game_state.difficulty {
+                        Some(level) => format!("{} ", level.name()),
+                        None => String::new(),
+                    },
+                    if game_state.library.is_custom(lang) {
+                        "custom "
+                    } else {
+                        ""
+                    }
+                ),
                 Style::default().fg(COLOR_CODE),
             ),
         ]));
     }
 
+    // The daily challenge, after the languages
+    let day = history::today();
+    let (daily_language, _) = state::daily_sample(day);
+    let (marker, name_style) = if game_state.menu_index == ALL.len() {
+        ("▸ ", Style::default().fg(COLOR_CYAN).bold())
+    } else {
+        ("  ", Style::default().fg(COLOR_GRAY))
+    };
+    let result = match game_state.history.daily_result(day) {
+        Some(run) => Span::styled(
+            format!("{:.0} WPM {:.0}%", run.wpm, run.accuracy),
+            Style::default().fg(COLOR_GREEN),
+        ),
+        None => Span::styled("not played", Style::default().fg(COLOR_CODE)),
+    };
+    lines.push(Line::from(vec![
+        Span::styled(marker, name_style),
+        Span::styled("📅 ", name_style),
+        Span::styled(format!("{:<12}", "Daily"), name_style),
+        Span::styled(
+            format!("{} · ", daily_language.name()),
+            Style::default().fg(COLOR_CODE),
+        ),
+        result,
+    ]));
+
     lines.push(Line::from(""));
     lines.push(Line::from(vec![
         Span::styled("↑↓", Style::default().fg(COLOR_CYAN).bold()),
         Span::styled(" navigate   ", Style::default().fg(COLOR_GRAY)),
         Span::styled("Enter", Style::default().fg(COLOR_GREEN).bold()),
         Span::styled(" start   ", Style::default().fg(COLOR_GRAY)),
+        Span::styled("b", Style::default().fg(COLOR_YELLOW).bold()),
+        Span::styled(" browse samples", Style::default().fg(COLOR_GRAY)),
+    ]));
+    lines.push(Line::from(vec![
+        Span::styled("d", Style::default().fg(COLOR_BLUE).bold()),
+        Span::styled(" difficulty   ", Style::default().fg(COLOR_GRAY)),
+        Span::styled("m", Style::default().fg(COLOR_GREEN).bold()),
+        Span::styled(" mode   ", Style::default().fg(COLOR_GRAY)),
+        Span::styled("e", Style::default().fg(COLOR_RED).bold()),
+        Span::styled(" stop on errors", Style::default().fg(COLOR_GRAY)),
+    ]));
+    lines.push(Line::from(vec![
+        Span::styled("s", Style::default().fg(COLOR_PURPLE).bold()),
+        Span::styled(" stats   ", Style::default().fg(COLOR_GRAY)),
+        Span::styled("h", Style::default().fg(COLOR_ORANGE).bold()),
+        Span::styled(" heatmap   ", Style::default().fg(COLOR_GRAY)),
         Span::styled("Esc", Style::default().fg(COLOR_RED).bold()),
         Span::styled(" quit", Style::default().fg(COLOR_GRAY)),
     ]));
@@ -103,8 +191,11 @@ fn draw_menu(f: &mut Frame, game_state: &GameState) {
 // TYPING SCREEN
 // ═══════════════════════════════════════════════════════════════════════════
 fn draw_typing(f: &mut Frame, game_state: &GameState) {
-    // Determine if we should show output (game over and has expected output)
-    let show_output = game_state.game_over && !game_state.expected_output.is_empty();
+    // Determine if we should show output (game over and has expected output).
+    // A timed run ends partway through a sample, so there is none to show.
+    let show_output = game_state.game_over
+        && !game_state.expected_output.is_empty()
+        && !game_state.run_mode().streams();
 
     let chunks = Layout::default()
         .direction(Direction::Vertical)
@@ -124,7 +215,7 @@ fn draw_typing(f: &mut Frame, game_state: &GameState) {
         .border_type(BorderType::Rounded)
         .border_style(Style::default().fg(COLOR_GOLD));
 
-    let title_text = vec![
+    let mut title_text = vec![
         Span::styled("━━━", Style::default().fg(COLOR_RED)),
         Span::styled("━━━", Style::default().fg(COLOR_ORANGE)),
         Span::styled(" ⌨ ", Style::default().fg(COLOR_GOLD).bold()),
@@ -143,6 +234,19 @@ fn draw_typing(f: &mut Frame, game_state: &GameState) {
         Span::styled("━━━", Style::default().fg(COLOR_BLUE)),
     ];
 
+    if let Some(replay) = game_state.replay {
+        let badge = if replay.paused {
+            " ⏸ PAUSED ".to_string()
+        } else {
+            format!(" ⏵ REPLAY {}x ", replay.speed)
+        };
+        title_text.push(Span::styled("  ", Style::default()));
+        title_text.push(Span::styled(
+            badge,
+            Style::default().fg(COLOR_DARK).bg(COLOR_PURPLE).bold(),
+        ));
+    }
+
     let title = Paragraph::new(Line::from(title_text))
         .block(title_block)
         .alignment(Alignment::Center);
@@ -153,20 +257,30 @@ fn draw_typing(f: &mut Frame, game_state: &GameState) {
     // ═══════════════════════════════════════════════════════════════════════
     let code_area_chunks = Layout::default()
         .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(5),                                           // Code display
+            Constraint::Length(if game_state.game_over { 8 } else { 0 }), // Results
+            Constraint::Length(3),                                        // Progress bar
+        ])
+        .split(chunks[1]);
+
+    // Results row: expected output (when there is one), speed chart, slowest sequences
+    let results_chunks = Layout::default()
+        .direction(Direction::Horizontal)
         .constraints(if show_output {
             vec![
-                Constraint::Min(5),    // Code display
-                Constraint::Length(8), // Output section
-                Constraint::Length(3), // Progress bar
+                Constraint::Ratio(1, 3),
+                Constraint::Ratio(5, 12),
+                Constraint::Ratio(1, 4),
             ]
         } else {
             vec![
-                Constraint::Min(5),    // Code display
-                Constraint::Length(0), // No output
-                Constraint::Length(3), // Progress bar
+                Constraint::Length(0),
+                Constraint::Ratio(3, 4),
+                Constraint::Ratio(1, 4),
             ]
         })
-        .split(chunks[1]);
+        .split(code_area_chunks[1]);
 
     // Calculate progress for title (with division by zero protection)
     let progress = if game_state.current_code_chars.is_empty() {
@@ -187,27 +301,72 @@ fn draw_typing(f: &mut Frame, game_state: &GameState) {
         COLOR_BLUE
     };
 
-    let code_block = Block::default()
-        .title(Line::from(vec![
-            Span::styled(" 📝 ", Style::default()),
-            Span::styled("Code ", Style::default().fg(title_color).bold()),
-            Span::styled("· ", Style::default().fg(COLOR_GRAY)),
-            Span::styled(
-                format!("{} ", game_state.language.spec().emoji),
-                Style::default(),
-            ),
-            Span::styled(
-                format!("{} ", game_state.language.name()),
-                Style::default().fg(COLOR_WHITE).bold(),
-            ),
-        ]))
+    let ghost = game_state.ghost_position();
+    let mut code_title = vec![
+        Span::styled(" 📝 ", Style::default()),
+        Span::styled("Code ", Style::default().fg(title_color).bold()),
+        Span::styled("· ", Style::default().fg(COLOR_GRAY)),
+        Span::styled(
+            format!("{} ", game_state.language.spec().emoji),
+            Style::default(),
+        ),
+        Span::styled(
+            format!("{} ", game_state.language.name()),
+            Style::default().fg(COLOR_WHITE).bold(),
+        ),
+        Span::styled("· ", Style::default().fg(COLOR_GRAY)),
+        Span::styled(
+            format!("{} ", game_state.sample.title),
+            Style::default().fg(COLOR_WHITE),
+        ),
+    ];
+    if let Some(difficulty) = game_state.sample.difficulty {
+        let level = Level::of(difficulty);
+        code_title.push(Span::styled("· ", Style::default().fg(COLOR_GRAY)));
+        code_title.push(Span::styled(
+            format!("{} ", level.name()),
+            Style::default().fg(level_color(level)),
+        ));
+    }
+    let mut code_block = Block::default()
+        .title(Line::from(code_title))
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
         .border_style(Style::default().fg(title_color))
         .padding(Padding::new(3, 3, 1, 1));
+    if let Some(source) = &game_state.sample.source {
+        code_block = code_block.title_bottom(
+            Line::from(Span::styled(
+                format!(" {source} "),
+                Style::default().fg(COLOR_GRAY),
+            ))
+            .right_aligned(),
+        );
+    }
+    if let (Some(pos), Some(best)) = (ghost, &game_state.ghost) {
+        let lead = game_state.user_input_chars.len() as i64 - pos as i64;
+        let lead_color = if lead >= 0 { COLOR_GREEN } else { COLOR_RED };
+        code_block = code_block.title_top(
+            Line::from(vec![
+                Span::styled(" 👻 ", Style::default()),
+                Span::styled(
+                    format!("{:.0} wpm ", best.wpm),
+                    Style::default().fg(COLOR_PURPLE),
+                ),
+                Span::styled("· ", Style::default().fg(COLOR_GRAY)),
+                Span::styled(format!("{lead:+} "), Style::default().fg(lead_color).bold()),
+            ])
+            .right_aligned(),
+        );
+    }
 
     let mut code_lines = Vec::new();
     let mut char_index = 0;
+    let cursor_bg = if game_state.blocked {
+        COLOR_RED
+    } else {
+        COLOR_CURSOR_BG
+    };
 
     let total_lines = game_state.current_code.lines().count();
     let line_num_width = total_lines.to_string().len().max(2);
@@ -224,7 +383,7 @@ fn draw_typing(f: &mut Frame, game_state: &GameState) {
         line_spans.push(Span::styled(" │ ", Style::default().fg(COLOR_DARK)));
 
         for ch in line.chars() {
-            let style = if char_index < game_state.user_input_chars.len() {
+            let mut style = if char_index < game_state.user_input_chars.len() {
                 // O(1) access using pre-computed Vec<char>
                 let user_char = game_state.user_input_chars[char_index];
                 if user_char == ch {
@@ -242,12 +401,16 @@ fn draw_typing(f: &mut Frame, game_state: &GameState) {
                         .add_modifier(Modifier::UNDERLINED)
                 }
             } else if char_index == game_state.user_input_chars.len() && !game_state.game_over {
-                // Current cursor position - bright yellow background for high visibility
-                Style::default().fg(COLOR_DARK).bg(COLOR_CURSOR_BG).bold()
+                // Current cursor position - bright yellow background for high visibility,
+                // red while a wrong key is being held back
+                Style::default().fg(COLOR_DARK).bg(cursor_bg).bold()
             } else {
                 // Untyped code - dimmed
                 Style::default().fg(COLOR_CODE)
             };
+            if ghost == Some(char_index) && char_index != game_state.user_input_chars.len() {
+                style = style.bg(COLOR_GHOST);
+            }
             line_spans.push(Span::styled(ch.to_string(), style));
             char_index += 1;
         }
@@ -257,30 +420,60 @@ fn draw_typing(f: &mut Frame, game_state: &GameState) {
             line_spans.push(Span::styled(
                 "↵",
                 Style::default()
-                    .fg(COLOR_CURSOR_BG)
+                    .fg(cursor_bg)
                     .bold()
                     .add_modifier(Modifier::SLOW_BLINK),
             ));
+        } else if ghost == Some(char_index) {
+            line_spans.push(Span::styled("↵", Style::default().fg(COLOR_PURPLE)));
         }
 
         code_lines.push(Line::from(line_spans));
         char_index += 1;
     }
 
-    let code_display = Paragraph::new(code_lines)
+    // Long lines are wrapped here rather than by the paragraph, so the rows
+    // counted for scrolling are exactly the rows drawn
+    let code_inner = code_block.inner(code_area_chunks[0]);
+    let code_rows: Vec<Vec<Line>> = code_lines
+        .into_iter()
+        .map(|line| wrap_line(line, code_inner.width as usize, line_num_width + 3))
+        .collect();
+
+    // Scroll long code so the cursor stays in the top third of the view
+    let rows: Vec<usize> = code_rows.iter().map(Vec::len).collect();
+    let cursor = game_state
+        .user_input_chars
+        .len()
+        .min(game_state.current_code_chars.len());
+    let cursor_line = game_state.current_code_chars[..cursor]
+        .iter()
+        .filter(|&&c| c == '\n')
+        .count();
+    let cursor_row: usize = rows.iter().take(cursor_line).sum();
+    let height = code_inner.height as usize;
+    let scroll = cursor_row
+        .saturating_sub(height / 3)
+        .min(rows.iter().sum::<usize>().saturating_sub(height));
+
+    let code_display = Paragraph::new(code_rows.into_iter().flatten().collect::<Vec<_>>())
         .block(code_block)
-        .wrap(Wrap { trim: false });
+        .scroll((scroll as u16, 0));
     f.render_widget(code_display, code_area_chunks[0]);
 
     // ═══════════════════════════════════════════════════════════════════════
-    // OUTPUT SECTION - Expected output when game is over (above progress bar)
+    // OUTPUT SECTION - Expected output when game is over (left of the results row)
     // ═══════════════════════════════════════════════════════════════════════
     if show_output {
+        let mut output_title = vec![
+            Span::styled(" 📤 ", Style::default()),
+            Span::styled("Output ", Style::default().fg(COLOR_GREEN).bold()),
+        ];
+        if game_state.sample.nondeterministic {
+            output_title.push(Span::styled("(may vary) ", Style::default().fg(COLOR_GRAY)));
+        }
         let output_block = Block::default()
-            .title(Line::from(vec![
-                Span::styled(" 📤 ", Style::default()),
-                Span::styled("Output ", Style::default().fg(COLOR_GREEN).bold()),
-            ]))
+            .title(Line::from(output_title))
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
             .border_style(Style::default().fg(COLOR_GREEN))
@@ -295,21 +488,22 @@ fn draw_typing(f: &mut Frame, game_state: &GameState) {
         let output_display = Paragraph::new(output_lines)
             .block(output_block)
             .wrap(Wrap { trim: false });
-        f.render_widget(output_display, code_area_chunks[1]);
+        f.render_widget(output_display, results_chunks[0]);
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // INSIGHTS SECTION - Speed over time and slowest key sequences of the run
+    // ═══════════════════════════════════════════════════════════════════════
+    if game_state.game_over {
+        render_speed_timeline(f, results_chunks[1], &game_state.wpm_timeline);
+        render_slowest(f, results_chunks[2], &game_state.run_ngrams);
     }
 
     // Progress bar / completion celebration (at code_area_chunks[2])
     if game_state.game_over {
         // Completion celebration UI
-        let rating = if game_state.wpm >= 80.0 && game_state.accuracy >= 98.0 {
-            ("🏆 LEGENDARY", COLOR_GOLD)
-        } else if game_state.wpm >= 60.0 && game_state.accuracy >= 95.0 {
-            ("⭐ EXCELLENT", COLOR_GREEN)
-        } else if game_state.wpm >= 40.0 && game_state.accuracy >= 85.0 {
-            ("✓ GOOD", COLOR_BLUE)
-        } else {
-            ("→ COMPLETE", COLOR_CYAN)
-        };
+        let rating = Rating::of(game_state.wpm, game_state.accuracy);
+        let rating = (rating.label(), rating_color(rating));
 
         let completion_block = Block::default()
             .borders(Borders::ALL)
@@ -325,32 +519,73 @@ fn draw_typing(f: &mut Frame, game_state: &GameState) {
         let mins = (elapsed_secs / 60.0).floor() as u32;
         let secs = (elapsed_secs % 60.0).floor() as u32;
 
-        let completion_text = Line::from(vec![
+        let mut completion_text = Line::from(vec![
             Span::styled(
                 format!(" {} ", rating.0),
                 Style::default().fg(rating.1).bold(),
             ),
+            Span::styled(
+                if game_state.new_record {
+                    "🎉 NEW BEST "
+                } else {
+                    ""
+                },
+                Style::default().fg(COLOR_GOLD).bold(),
+            ),
             Span::styled(" │ ", Style::default().fg(COLOR_GRAY)),
             Span::styled(
-                format!("⚡ {:.0} wpm  ", game_state.wpm),
+                format!("⚡ {:.0} wpm", game_state.wpm),
                 Style::default().fg(COLOR_WHITE).bold(),
             ),
             Span::styled(
-                format!("🎯 {:.1}%  ", game_state.accuracy),
+                format!(" (raw {:.0})  ", game_state.raw_wpm),
+                Style::default().fg(COLOR_GRAY),
+            ),
+            Span::styled(
+                format!("🎯 {:.1}%", game_state.accuracy),
                 Style::default().fg(COLOR_WHITE).bold(),
             ),
             Span::styled(
-                format!("⏱ {}:{:02}  ", mins, secs),
+                format!(" (raw {:.1}%)  ", game_state.raw_accuracy),
+                Style::default().fg(COLOR_GRAY),
+            ),
+            Span::styled(
+                format!("✎ {} fixed", game_state.corrected_errors),
+                Style::default().fg(COLOR_YELLOW).bold(),
+            ),
+            Span::styled(" · ", Style::default().fg(COLOR_GRAY)),
+            Span::styled(
+                format!("{} left  ", game_state.uncorrected_errors),
+                Style::default().fg(COLOR_RED).bold(),
+            ),
+            Span::styled(
+                format!("⏱ {}:{:02} ", mins, secs),
                 Style::default().fg(COLOR_WHITE).bold(),
             ),
-            Span::styled("│ ", Style::default().fg(COLOR_GRAY)),
-            Span::styled("↵", Style::default().fg(COLOR_CYAN).bold()),
-            Span::styled(" next  ", Style::default().fg(COLOR_GRAY)),
-            Span::styled("↑↓", Style::default().fg(COLOR_CYAN).bold()),
-            Span::styled(" language  ", Style::default().fg(COLOR_GRAY)),
-            Span::styled("ESC", Style::default().fg(COLOR_RED).bold()),
-            Span::styled(" quit ", Style::default().fg(COLOR_GRAY)),
         ]);
+        match game_state.run_mode() {
+            Mode::SuddenDeath => completion_text.spans.push(Span::styled(
+                format!("· 🔥 {} in a row ", game_state.streak()),
+                Style::default().fg(COLOR_ORANGE).bold(),
+            )),
+            Mode::Timed(_) => {
+                let samples = game_state.banked.samples;
+                let s = if samples == 1 { "" } else { "s" };
+                completion_text.spans.push(Span::styled(
+                    format!("· {samples} sample{s} finished "),
+                    Style::default().fg(COLOR_GRAY),
+                ));
+            }
+            Mode::Daily(_) => completion_text.spans.push(Span::styled(
+                "· 📅 daily challenge ",
+                Style::default().fg(COLOR_GOLD).bold(),
+            )),
+            Mode::Sample if game_state.daily.is_some() => completion_text.spans.push(Span::styled(
+                "· daily practice ",
+                Style::default().fg(COLOR_GRAY),
+            )),
+            Mode::Sample => {}
+        }
 
         let completion_para = Paragraph::new(completion_text)
             .block(completion_block)
@@ -396,9 +631,10 @@ fn draw_typing(f: &mut Frame, game_state: &GameState) {
     let stats_chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([
-            Constraint::Ratio(1, 3),
-            Constraint::Ratio(1, 3),
-            Constraint::Ratio(1, 3),
+            Constraint::Ratio(1, 4),
+            Constraint::Ratio(1, 4),
+            Constraint::Ratio(1, 4),
+            Constraint::Ratio(1, 4),
         ])
         .split(stats_chunk);
 
@@ -407,7 +643,7 @@ fn draw_typing(f: &mut Frame, game_state: &GameState) {
         if let Some(end_time) = game_state.end_time {
             end_time.duration_since(first_input)
         } else {
-            Instant::now().duration_since(first_input)
+            game_state.now().duration_since(first_input)
         }
     } else {
         std::time::Duration::from_secs(0)
@@ -443,16 +679,46 @@ fn draw_typing(f: &mut Frame, game_state: &GameState) {
     } else {
         COLOR_RED
     };
-    render_stat_card(
-        f,
-        stats_chunks[1],
-        "🎯 ACC",
-        &format!("{:.1}%", game_state.accuracy),
-        acc_color,
-    );
+    if game_state.run_mode() == Mode::SuddenDeath {
+        // Every char so far is right, so the streak says more than accuracy
+        let streak = game_state.streak().to_string();
+        render_stat_card(f, stats_chunks[1], "🔥 STREAK", &streak, COLOR_ORANGE);
+    } else {
+        render_stat_card(
+            f,
+            stats_chunks[1],
+            "🎯 ACC",
+            &format!("{:.1}%", game_state.accuracy),
+            acc_color,
+        );
+    }
 
-    // Time Card
-    render_stat_card(f, stats_chunks[2], "⏱ TIME", &timer_str, COLOR_CYAN);
+    // Time Card: counts down in a timed run
+    match game_state.time_left() {
+        Some(left) => {
+            let secs = left.as_secs_f32().ceil() as u64;
+            let color = if secs <= 5 { COLOR_RED } else { COLOR_CYAN };
+            let left = format!("{}:{:02}", secs / 60, secs % 60);
+            render_stat_card(f, stats_chunks[2], "⏱ LEFT", &left, color);
+        }
+        None => render_stat_card(f, stats_chunks[2], "⏱ TIME", &timer_str, COLOR_CYAN),
+    }
+
+    // Personal best Card
+    if game_state.run_mode() == Mode::SuddenDeath {
+        let best = game_state
+            .best_streak
+            .map_or("—".to_string(), |b| b.to_string());
+        render_stat_card(f, stats_chunks[3], "🏆 BEST STREAK", &best, COLOR_GOLD);
+    } else {
+        render_best_card(
+            f,
+            stats_chunks[3],
+            game_state.sample_best,
+            game_state.language_best,
+            game_state.language.name(),
+        );
+    }
 
     // Controls
     let controls_area = Rect {
@@ -462,27 +728,71 @@ fn draw_typing(f: &mut Frame, game_state: &GameState) {
         height: 1,
     };
 
-    let controls_text = if game_state.game_over {
+    let controls_text = if game_state.replay.is_some() && game_state.game_over {
         vec![
+            Span::styled("any key", Style::default().fg(COLOR_CYAN).bold()),
+            Span::styled(" end replay", Style::default().fg(COLOR_GRAY)),
+        ]
+    } else if game_state.replay.is_some() {
+        vec![
+            Span::styled("SPACE", Style::default().fg(COLOR_CYAN).bold()),
+            Span::styled(" pause  ", Style::default().fg(COLOR_GRAY)),
+            Span::styled("1 2 4", Style::default().fg(COLOR_YELLOW).bold()),
+            Span::styled(" speed  ", Style::default().fg(COLOR_GRAY)),
+            Span::styled("ESC", Style::default().fg(COLOR_RED).bold()),
+            Span::styled(" stop", Style::default().fg(COLOR_GRAY)),
+        ]
+    } else if game_state.game_over {
+        let next = if game_state.daily.is_some() {
+            " practice  "
+        } else {
+            " next  "
+        };
+        let mut spans = vec![
             Span::styled("↵", Style::default().fg(COLOR_CYAN).bold()),
-            Span::styled(" next  ", Style::default().fg(COLOR_GRAY)),
+            Span::styled(next, Style::default().fg(COLOR_GRAY)),
+        ];
+        if game_state.has_next_part() {
+            spans.push(Span::styled("n", Style::default().fg(COLOR_GREEN).bold()));
+            spans.push(Span::styled(
+                " next part  ",
+                Style::default().fg(COLOR_GRAY),
+            ));
+        }
+        if !game_state.run_mode().streams() {
+            spans.push(Span::styled("r", Style::default().fg(COLOR_PURPLE).bold()));
+            spans.push(Span::styled(" replay  ", Style::default().fg(COLOR_GRAY)));
+        }
+        spans.extend([
             Span::styled("↑↓", Style::default().fg(COLOR_CYAN).bold()),
             Span::styled(" language  ", Style::default().fg(COLOR_GRAY)),
             Span::styled("ESC", Style::default().fg(COLOR_RED).bold()),
             Span::styled(" quit", Style::default().fg(COLOR_GRAY)),
-        ]
+        ]);
+        spans
     } else if game_state.first_input_time.is_none() {
-        vec![
-            Span::styled("◀▶", Style::default().fg(COLOR_CYAN).bold()),
-            Span::styled(" sample  ", Style::default().fg(COLOR_GRAY)),
+        let mut spans = Vec::new();
+        // The daily challenge is one fixed sample
+        if game_state.daily.is_none() {
+            spans.extend([
+                Span::styled("◀▶", Style::default().fg(COLOR_CYAN).bold()),
+                Span::styled(" sample  ", Style::default().fg(COLOR_GRAY)),
+            ]);
+        }
+        spans.extend([
             Span::styled("↑↓", Style::default().fg(COLOR_CYAN).bold()),
             Span::styled(" language  ", Style::default().fg(COLOR_GRAY)),
             Span::styled("TAB", Style::default().fg(COLOR_YELLOW).bold()),
             Span::styled(" indent  ", Style::default().fg(COLOR_GRAY)),
             Span::styled("ESC", Style::default().fg(COLOR_RED).bold()),
             Span::styled(" quit", Style::default().fg(COLOR_GRAY)),
-        ]
+        ]);
+        spans
     } else {
+        let restart = match game_state.run_mode() {
+            Mode::Daily(_) => " give up",
+            _ => " restart",
+        };
         vec![
             Span::styled("↵", Style::default().fg(COLOR_GREEN).bold()),
             Span::styled(" newline  ", Style::default().fg(COLOR_GRAY)),
@@ -491,7 +801,7 @@ fn draw_typing(f: &mut Frame, game_state: &GameState) {
             Span::styled("⌫", Style::default().fg(COLOR_BLUE).bold()),
             Span::styled(" delete  ", Style::default().fg(COLOR_GRAY)),
             Span::styled("ESC", Style::default().fg(COLOR_RED).bold()),
-            Span::styled(" restart", Style::default().fg(COLOR_GRAY)),
+            Span::styled(restart, Style::default().fg(COLOR_GRAY)),
         ]
     };
 
@@ -499,6 +809,720 @@ fn draw_typing(f: &mut Frame, game_state: &GameState) {
     f.render_widget(controls, controls_area);
 }
 
+// ═══════════════════════════════════════════════════════════════════════════
+// STATS SCREEN — progress over time from the run history
+// ═══════════════════════════════════════════════════════════════════════════
+fn draw_stats(f: &mut Frame, game_state: &GameState) {
+    let runs = game_state.stats_runs();
+    let summary = Summary::of(&runs);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Length(3), // Title + language filter
+            Constraint::Length(8), // Totals + rating distribution
+            Constraint::Min(6),    // WPM trend
+            Constraint::Min(6),    // Accuracy trend
+            Constraint::Length(1), // Controls
+        ])
+        .split(f.area());
+
+    let filter = match game_state.stats_language() {
+        Some(lang) => format!("{} {}", lang.spec().emoji, lang.name()),
+        None => "All languages".to_string(),
+    };
+    let title = Paragraph::new(Line::from(vec![
+        Span::styled("📊 ", Style::default()),
+        Span::styled("Statistics", Style::default().fg(COLOR_CYAN).bold()),
+        Span::styled("  ◀ ", Style::default().fg(COLOR_GRAY)),
+        Span::styled(filter, Style::default().fg(COLOR_WHITE).bold()),
+        Span::styled(" ▶", Style::default().fg(COLOR_GRAY)),
+    ]))
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(COLOR_GOLD)),
+    )
+    .alignment(Alignment::Center);
+    f.render_widget(title, chunks[0]);
+
+    let top = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Ratio(2, 5),
+            Constraint::Ratio(2, 5),
+            Constraint::Ratio(1, 5),
+        ])
+        .split(chunks[1]);
+
+    // Totals
+    let total_secs = summary.total_secs as u64;
+    let totals = [
+        ("Runs", summary.runs.to_string()),
+        ("Samples practiced", summary.samples.to_string()),
+        (
+            "Time spent",
+            format!("{}h {:02}m", total_secs / 3600, (total_secs % 3600) / 60),
+        ),
+        (
+            "Average",
+            format!("{:.0} wpm · {:.1}%", summary.avg_wpm, summary.avg_accuracy),
+        ),
+        ("Best", format!("{:.0} wpm", summary.best_wpm)),
+    ];
+    let total_lines: Vec<Line> = totals
+        .into_iter()
+        .map(|(label, value)| {
+            Line::from(vec![
+                Span::styled(format!("{label:<18}"), Style::default().fg(COLOR_GRAY)),
+                Span::styled(value, Style::default().fg(COLOR_WHITE).bold()),
+            ])
+        })
+        .collect();
+    f.render_widget(
+        Paragraph::new(total_lines).block(stats_block("Totals", COLOR_BLUE)),
+        top[0],
+    );
+
+    // Rating distribution
+    let bars: Vec<Bar> = Rating::ALL
+        .iter()
+        .zip(summary.ratings)
+        .map(|(&rating, count)| {
+            Bar::default()
+                .value(count as u64)
+                .label(Line::from(rating.label()))
+                .style(Style::default().fg(rating_color(rating)))
+                .value_style(Style::default().fg(COLOR_DARK).bg(rating_color(rating)))
+        })
+        .collect();
+    let ratings = BarChart::default()
+        .block(stats_block("Ratings", COLOR_PURPLE))
+        .direction(Direction::Horizontal)
+        .bar_width(1)
+        .bar_gap(0)
+        .data(BarGroup::default().bars(&bars));
+    f.render_widget(ratings, top[1]);
+
+    // Slowest sequences across all runs (ignoring ones only seen a couple of times)
+    let slowest: Vec<Line> = stats::slowest_ngrams(runs.iter().flat_map(|r| &r.ngrams), 3)
+        .into_iter()
+        .take(top[2].height.saturating_sub(2) as usize)
+        .map(|(seq, ms)| {
+            Line::from(vec![
+                Span::styled(format!("{seq:<4}"), Style::default().fg(COLOR_WHITE).bold()),
+                Span::styled(format!("{ms:>5.0}ms"), Style::default().fg(COLOR_GRAY)),
+            ])
+        })
+        .collect();
+    f.render_widget(
+        Paragraph::new(slowest).block(stats_block("🐢 Slowest", COLOR_YELLOW)),
+        top[2],
+    );
+
+    if runs.is_empty() {
+        let empty = Paragraph::new(Line::from(Span::styled(
+            "No runs recorded yet — finish a sample to start tracking progress.",
+            Style::default().fg(COLOR_GRAY),
+        )))
+        .alignment(Alignment::Center)
+        .block(stats_block("Trends", COLOR_GRAY));
+        let area = Rect {
+            height: chunks[2].height + chunks[3].height,
+            ..chunks[2]
+        };
+        f.render_widget(empty, area);
+    } else {
+        render_trend(f, chunks[2], &runs, "⚡ WPM", COLOR_GREEN, false, |r| {
+            r.wpm
+        });
+        render_trend(
+            f,
+            chunks[3],
+            &runs,
+            "🎯 Accuracy",
+            COLOR_CYAN,
+            true,
+            |r| r.accuracy,
+        );
+    }
+
+    let controls = Paragraph::new(Line::from(vec![
+        Span::styled("◀▶", Style::default().fg(COLOR_CYAN).bold()),
+        Span::styled(" language  ", Style::default().fg(COLOR_GRAY)),
+        Span::styled("ESC", Style::default().fg(COLOR_RED).bold()),
+        Span::styled(" back", Style::default().fg(COLOR_GRAY)),
+    ]))
+    .alignment(Alignment::Center);
+    f.render_widget(controls, chunks[4]);
+}
+
+/// Line chart of one metric per run, oldest to newest. Percentages are drawn
+/// from the nearest ten below the worst run up to 100.
+fn render_trend(
+    f: &mut Frame,
+    area: Rect,
+    runs: &[&RunRecord],
+    title: &str,
+    color: Color,
+    percent: bool,
+    metric: fn(&RunRecord) -> f32,
+) {
+    let points: Vec<(f64, f64)> = runs
+        .iter()
+        .enumerate()
+        .map(|(i, r)| ((i + 1) as f64, metric(r) as f64))
+        .collect();
+    let (y_min, y_max) = if percent {
+        let min = points.iter().map(|p| p.1).fold(100.0, f64::min);
+        ((min / 10.0).floor() * 10.0, 100.0)
+    } else {
+        let max = points.iter().map(|p| p.1).fold(0.0, f64::max);
+        (0.0, (max * 1.1).max(10.0).ceil())
+    };
+    let x_max = points.len().max(2) as f64;
+
+    let first = history::format_date(runs[0].timestamp);
+    let last = history::format_date(runs[runs.len() - 1].timestamp);
+
+    let dataset = Dataset::default()
+        .marker(symbols::Marker::Braille)
+        .graph_type(GraphType::Line)
+        .style(Style::default().fg(color))
+        .data(&points);
+    let chart = Chart::new(vec![dataset])
+        .block(stats_block(title, color))
+        .x_axis(
+            Axis::default()
+                .style(Style::default().fg(COLOR_GRAY))
+                .bounds([1.0, x_max])
+                .labels(vec![Span::raw(first), Span::raw(last)]),
+        )
+        .y_axis(
+            Axis::default()
+                .style(Style::default().fg(COLOR_GRAY))
+                .bounds([y_min, y_max])
+                .labels(vec![
+                    Span::raw(format!("{y_min:.0}")),
+                    Span::raw(format!("{y_max:.0}")),
+                ]),
+        );
+    f.render_widget(chart, area);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// HEATMAP SCREEN — mistyped keys over a US keyboard
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Keyboard rows as (plain, shifted) characters and the row's indent in columns.
+const KEYBOARD_ROWS: [(&str, &str, u16); 4] = [
+    ("`1234567890-=", "~!@#$%^&*()_+", 0),
+    ("qwertyuiop[]\\", "QWERTYUIOP{}|", 3),
+    ("asdfghjkl;'", "ASDFGHJKL:\"", 5),
+    ("zxcvbnm,./", "ZXCVBNM<>?", 8),
+];
+const KEY_WIDTH: u16 = 5;
+const KEY_HEIGHT: u16 = 2;
+/// Widest row: the `qwerty` row's indent plus 13 keys and their gaps.
+const KEYBOARD_WIDTH: u16 = 3 + 13 * (KEY_WIDTH + 1);
+
+fn draw_heatmap(f: &mut Frame, game_state: &GameState) {
+    let runs = game_state.stats_runs();
+    let misses = KeyMisses::of(&runs);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Length(3),  // Title + language filter
+            Constraint::Length(16), // Keyboard
+            Constraint::Min(4),     // Most missed + confusions
+            Constraint::Length(1),  // Controls
+        ])
+        .split(f.area());
+
+    let filter = match game_state.stats_language() {
+        Some(lang) => format!("{} {}", lang.spec().emoji, lang.name()),
+        None => "All languages".to_string(),
+    };
+    let title = Paragraph::new(Line::from(vec![
+        Span::styled("🔥 ", Style::default()),
+        Span::styled("Key Heatmap", Style::default().fg(COLOR_ORANGE).bold()),
+        Span::styled("  ◀ ", Style::default().fg(COLOR_GRAY)),
+        Span::styled(filter, Style::default().fg(COLOR_WHITE).bold()),
+        Span::styled(" ▶", Style::default().fg(COLOR_GRAY)),
+    ]))
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(COLOR_GOLD)),
+    )
+    .alignment(Alignment::Center);
+    f.render_widget(title, chunks[0]);
+
+    // Keyboard
+    let keyboard_block = stats_block("Misses per key", COLOR_ORANGE);
+    let keyboard = keyboard_block.inner(chunks[1]);
+    f.render_widget(keyboard_block, chunks[1]);
+
+    let max = misses.by_char.values().copied().max().unwrap_or(0);
+    let left = keyboard.x + keyboard.width.saturating_sub(KEYBOARD_WIDTH) / 2;
+    let row_y = |row: u16| keyboard.y + row * (KEY_HEIGHT + 1);
+    for (row, (plain, shifted, indent)) in KEYBOARD_ROWS.iter().enumerate() {
+        for (col, (p, s)) in plain.chars().zip(shifted.chars()).enumerate() {
+            let x = left + indent + col as u16 * (KEY_WIDTH + 1);
+            let label = if p.is_ascii_alphabetic() {
+                [String::new(), s.to_string()]
+            } else {
+                [s.to_string(), p.to_string()]
+            };
+            let count = misses.for_key(p, Some(s));
+            render_key(
+                f,
+                keyboard,
+                x,
+                row_y(row as u16),
+                KEY_WIDTH,
+                label,
+                count,
+                max,
+            );
+        }
+    }
+    // Enter sits at the end of the home row, space under the bottom row.
+    let (home, _, home_indent) = KEYBOARD_ROWS[2];
+    let enter_x = left + home_indent + home.len() as u16 * (KEY_WIDTH + 1);
+    let enter = ["".to_string(), "Enter ↵".to_string()];
+    let count = misses.for_key('\n', None);
+    render_key(
+        f,
+        keyboard,
+        enter_x,
+        row_y(2),
+        KEY_WIDTH * 2,
+        enter,
+        count,
+        max,
+    );
+    let space = ["".to_string(), "space".to_string()];
+    let count = misses.for_key(' ', None);
+    let space_x = left + 3 * (KEY_WIDTH + 1) + 5;
+    render_key(
+        f,
+        keyboard,
+        space_x,
+        row_y(4),
+        KEY_WIDTH * 6,
+        space,
+        count,
+        max,
+    );
+
+    // Most missed keys + common confusions
+    let bottom = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)])
+        .split(chunks[2]);
+    let rows = bottom[0].height.saturating_sub(2) as usize;
+
+    let missed: Vec<Line> = misses
+        .most_missed()
+        .into_iter()
+        .take(rows)
+        .map(|(c, n)| {
+            Line::from(vec![
+                Span::styled(
+                    format!("{:<8}", key_label(c)),
+                    Style::default().fg(COLOR_WHITE).bold(),
+                ),
+                Span::styled(
+                    format!("{n} {}", if n == 1 { "miss" } else { "misses" }),
+                    Style::default().fg(heat_color(n, max)),
+                ),
+            ])
+        })
+        .collect();
+    let confusions: Vec<Line> = misses
+        .confusions
+        .iter()
+        .take(rows)
+        .map(|&(expected, typed, n)| {
+            Line::from(vec![
+                Span::styled(
+                    format!("{:<6}", key_label(expected)),
+                    Style::default().fg(COLOR_GREEN).bold(),
+                ),
+                Span::styled("→ ", Style::default().fg(COLOR_GRAY)),
+                Span::styled(
+                    format!("{:<6}", key_label(typed)),
+                    Style::default().fg(COLOR_RED).bold(),
+                ),
+                Span::styled(format!("×{n}"), Style::default().fg(COLOR_WHITE)),
+            ])
+        })
+        .collect();
+    let empty = |lines: Vec<Line<'static>>| {
+        if lines.is_empty() {
+            vec![Line::from(Span::styled(
+                "No mistakes recorded yet",
+                Style::default().fg(COLOR_GRAY),
+            ))]
+        } else {
+            lines
+        }
+    };
+    f.render_widget(
+        Paragraph::new(empty(missed)).block(stats_block("Most missed", COLOR_RED)),
+        bottom[0],
+    );
+    f.render_widget(
+        Paragraph::new(empty(confusions)).block(stats_block("Expected → typed", COLOR_YELLOW)),
+        bottom[1],
+    );
+
+    let controls = Paragraph::new(Line::from(vec![
+        Span::styled("◀▶", Style::default().fg(COLOR_CYAN).bold()),
+        Span::styled(" language  ", Style::default().fg(COLOR_GRAY)),
+        Span::styled("ESC", Style::default().fg(COLOR_RED).bold()),
+        Span::styled(" back", Style::default().fg(COLOR_GRAY)),
+    ]))
+    .alignment(Alignment::Center);
+    f.render_widget(controls, chunks[3]);
+}
+
+/// Draw one key cap at `(x, y)`, skipping keys that do not fit in `bounds`.
+#[allow(clippy::too_many_arguments)]
+fn render_key(
+    f: &mut Frame,
+    bounds: Rect,
+    x: u16,
+    y: u16,
+    width: u16,
+    label: [String; 2],
+    count: u32,
+    max: u32,
+) {
+    let area = Rect {
+        x,
+        y,
+        width,
+        height: KEY_HEIGHT,
+    };
+    if area.right() > bounds.right() || area.bottom() > bounds.bottom() {
+        return;
+    }
+    let style = if count == 0 {
+        Style::default().fg(COLOR_GRAY).bg(COLOR_SURFACE)
+    } else {
+        Style::default()
+            .fg(COLOR_DARK)
+            .bg(heat_color(count, max))
+            .bold()
+    };
+    let [top, bottom] = label;
+    let key = Paragraph::new(vec![Line::from(top), Line::from(bottom)])
+        .style(style)
+        .alignment(Alignment::Center);
+    f.render_widget(key, area);
+}
+
+/// Yellow for the occasional miss, shading to red for the most-missed key.
+fn heat_color(count: u32, max: u32) -> Color {
+    let t = if max == 0 {
+        0.0
+    } else {
+        count as f32 / max as f32
+    };
+    let lerp = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+    Color::Rgb(lerp(224, 247), lerp(175, 80), lerp(104, 90))
+}
+
+/// Printable name for a character in miss lists.
+fn key_label(c: char) -> String {
+    match c {
+        ' ' => "space".to_string(),
+        '\n' => "enter".to_string(),
+        '\t' => "tab".to_string(),
+        c => format!("'{c}'"),
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SAMPLE BROWSER — search the samples of one language and pick one
+// ═══════════════════════════════════════════════════════════════════════════
+fn draw_browser(f: &mut Frame, game_state: &GameState) {
+    let language = game_state.browser_language();
+    let samples = game_state.library.samples(language);
+    let matches = game_state.browser_matches();
+    let selected = game_state.browser_selection();
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Length(3), // Search
+            Constraint::Min(5),    // List + preview
+            Constraint::Length(1), // Controls
+        ])
+        .split(f.area());
+
+    let search = Paragraph::new(Line::from(vec![
+        Span::styled(" 🔍 ", Style::default()),
+        Span::styled(
+            game_state.browser_query.as_str(),
+            Style::default().fg(COLOR_WHITE).bold(),
+        ),
+        Span::styled("▏", Style::default().fg(COLOR_CURSOR_BG)),
+    ]))
+    .block(
+        Block::default()
+            .title(Line::from(vec![
+                Span::styled(" 📚 ", Style::default()),
+                Span::styled("Samples ", Style::default().fg(COLOR_CYAN).bold()),
+                Span::styled("· ", Style::default().fg(COLOR_GRAY)),
+                Span::styled(
+                    format!("{} {} ", language.spec().emoji, language.name()),
+                    Style::default().fg(COLOR_WHITE).bold(),
+                ),
+            ]))
+            .title_top(
+                Line::from(Span::styled(
+                    format!(" {}/{} ", matches.len(), samples.len()),
+                    Style::default().fg(COLOR_GRAY),
+                ))
+                .right_aligned(),
+            )
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(COLOR_GOLD)),
+    );
+    f.render_widget(search, chunks[0]);
+
+    let body = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Ratio(2, 5), Constraint::Ratio(3, 5)])
+        .split(chunks[1]);
+
+    // Titles, scrolled so the highlighted one stays visible
+    let list_block = stats_block("Titles", COLOR_BLUE);
+    let height = list_block.inner(body[0]).height as usize;
+    let offset = (game_state.browser_index + 1).saturating_sub(height);
+    let mut titles: Vec<Line> = matches
+        .iter()
+        .enumerate()
+        .skip(offset)
+        .take(height)
+        .map(|(i, &idx)| {
+            let sample = &samples[idx];
+            let (marker, style) = if i == game_state.browser_index {
+                ("▸ ", Style::default().fg(COLOR_CYAN).bold())
+            } else {
+                ("  ", Style::default().fg(COLOR_WHITE))
+            };
+            let mut spans = vec![Span::styled(marker, style)];
+            if let Some(difficulty) = sample.difficulty {
+                let level = Level::of(difficulty);
+                spans.push(Span::styled(
+                    format!("{:<7}", level.name()),
+                    Style::default().fg(level_color(level)),
+                ));
+            }
+            spans.push(Span::styled(sample.title.to_string(), style));
+            for tag in sample.tags.iter() {
+                spans.push(Span::styled(
+                    format!(" #{tag}"),
+                    Style::default().fg(COLOR_PURPLE),
+                ));
+            }
+            Line::from(spans)
+        })
+        .collect();
+    if titles.is_empty() {
+        titles.push(Line::from(Span::styled(
+            "No samples match",
+            Style::default().fg(COLOR_GRAY),
+        )));
+    }
+    f.render_widget(Paragraph::new(titles).block(list_block), body[0]);
+
+    // Preview of the highlighted sample
+    let mut preview_block = stats_block("Preview", COLOR_GREEN);
+    let mut preview = Vec::new();
+    if let Some(sample) = selected {
+        preview_block = stats_block(&sample.title, COLOR_GREEN);
+        if let Some(source) = &sample.source {
+            preview_block = preview_block.title_bottom(
+                Line::from(Span::styled(
+                    format!(" {source} "),
+                    Style::default().fg(COLOR_GRAY),
+                ))
+                .right_aligned(),
+            );
+        }
+        let colors = syntax::highlight(&sample.code, language.spec());
+        let mut colors = colors.iter();
+        for line in sample.code.lines() {
+            // One span per run of same-coloured characters
+            let mut spans: Vec<Span> = Vec::new();
+            let mut run = String::new();
+            let mut run_color = COLOR_CODE;
+            for ch in line.chars() {
+                let color = colors.next().map_or(COLOR_CODE, |t| t.color());
+                if color != run_color && !run.is_empty() {
+                    spans.push(Span::styled(
+                        std::mem::take(&mut run),
+                        Style::default().fg(run_color),
+                    ));
+                }
+                run_color = color;
+                run.push(ch);
+            }
+            spans.push(Span::styled(run, Style::default().fg(run_color)));
+            colors.next(); // the newline
+            preview.push(Line::from(spans));
+        }
+    }
+    f.render_widget(Paragraph::new(preview).block(preview_block), body[1]);
+
+    let controls = Paragraph::new(Line::from(vec![
+        Span::styled("↑↓", Style::default().fg(COLOR_CYAN).bold()),
+        Span::styled(" select  ", Style::default().fg(COLOR_GRAY)),
+        Span::styled("ENTER", Style::default().fg(COLOR_GREEN).bold()),
+        Span::styled(" start  ", Style::default().fg(COLOR_GRAY)),
+        Span::styled("type", Style::default().fg(COLOR_YELLOW).bold()),
+        Span::styled(" to search  ", Style::default().fg(COLOR_GRAY)),
+        Span::styled("TAB", Style::default().fg(COLOR_BLUE).bold()),
+        Span::styled(
+            if game_state.browser_sorted {
+                " library order  "
+            } else {
+                " easiest first  "
+            },
+            Style::default().fg(COLOR_GRAY),
+        ),
+        Span::styled("ESC", Style::default().fg(COLOR_RED).bold()),
+        Span::styled(" clear / back", Style::default().fg(COLOR_GRAY)),
+    ]))
+    .alignment(Alignment::Center);
+    f.render_widget(controls, chunks[2]);
+}
+
+/// Split `line` into rows at most `width` columns wide, breaking between spans
+/// (code is drawn a char per span) rather than at words. Rows after the first
+/// are indented by `indent`, to line up past the line-number gutter.
+fn wrap_line(line: Line, width: usize, indent: usize) -> Vec<Line> {
+    let indent = indent.min(width / 2);
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut used = 0;
+    for span in line.spans {
+        let span_width = span.width();
+        if used + span_width > width && used > indent {
+            rows.push(Line::from(std::mem::take(&mut row)));
+            row.push(Span::raw(" ".repeat(indent)));
+            used = indent;
+        }
+        used += span_width;
+        row.push(span);
+    }
+    rows.push(Line::from(row));
+    rows
+}
+
+fn level_color(level: Level) -> Color {
+    match level {
+        Level::Easy => COLOR_GREEN,
+        Level::Medium => COLOR_YELLOW,
+        Level::Hard => COLOR_RED,
+    }
+}
+
+fn stats_block(title: &str, color: Color) -> Block<'_> {
+    Block::default()
+        .title(Span::styled(
+            format!(" {title} "),
+            Style::default().fg(color).bold(),
+        ))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(color))
+        .padding(Padding::horizontal(1))
+}
+
+fn rating_color(rating: Rating) -> Color {
+    match rating {
+        Rating::Legendary => COLOR_GOLD,
+        Rating::Excellent => COLOR_GREEN,
+        Rating::Good => COLOR_BLUE,
+        Rating::Complete => COLOR_CYAN,
+    }
+}
+
+/// Per-second WPM of the finished run, titled with its consistency and burst peak.
+fn render_speed_timeline(f: &mut Frame, area: Rect, timeline: &[f32]) {
+    let title = format!(
+        "📈 Speed · steady {:.0}% · burst {:.0}",
+        stats::consistency(timeline),
+        stats::burst(timeline)
+    );
+    let points: Vec<(f64, f64)> = timeline
+        .iter()
+        .enumerate()
+        .map(|(i, &wpm)| ((i + 1) as f64, wpm as f64))
+        .collect();
+    let y_max = (stats::burst(timeline) as f64 * 1.1).max(10.0).ceil();
+    let x_max = points.len().max(2) as f64;
+
+    let dataset = Dataset::default()
+        .marker(symbols::Marker::Braille)
+        .graph_type(GraphType::Line)
+        .style(Style::default().fg(COLOR_GREEN))
+        .data(&points);
+    let chart = Chart::new(vec![dataset])
+        .block(stats_block(&title, COLOR_GREEN))
+        .x_axis(
+            Axis::default()
+                .style(Style::default().fg(COLOR_GRAY))
+                .bounds([1.0, x_max])
+                .labels(vec![Span::raw("1s"), Span::raw(format!("{x_max:.0}s"))]),
+        )
+        .y_axis(
+            Axis::default()
+                .style(Style::default().fg(COLOR_GRAY))
+                .bounds([0.0, y_max])
+                .labels(vec![Span::raw("0"), Span::raw(format!("{y_max:.0}"))]),
+        );
+    f.render_widget(chart, area);
+}
+
+/// The run's slowest bigrams/trigrams, one per line.
+fn render_slowest(f: &mut Frame, area: Rect, ngrams: &[(String, f32, u32)]) {
+    let rows = area.height.saturating_sub(2) as usize;
+    let mut lines: Vec<Line> = stats::slowest_ngrams(ngrams, 1)
+        .into_iter()
+        .take(rows)
+        .map(|(seq, ms)| {
+            Line::from(vec![
+                Span::styled(format!("{seq:<4}"), Style::default().fg(COLOR_WHITE).bold()),
+                Span::styled(format!("{ms:>6.0}ms"), Style::default().fg(COLOR_GRAY)),
+            ])
+        })
+        .collect();
+    if lines.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "—",
+            Style::default().fg(COLOR_GRAY),
+        )));
+    }
+    f.render_widget(
+        Paragraph::new(lines).block(stats_block("🐢 Slowest", COLOR_YELLOW)),
+        area,
+    );
+}
+
 /// Calculate a centered popup area
 fn popup_area(area: Rect, percent_x: u16, percent_y: u16) -> Rect {
     let vertical = Layout::vertical([Constraint::Percentage(percent_y)]).flex(Flex::Center);
@@ -588,10 +1612,44 @@ fn render_stat_card(f: &mut Frame, area: Rect, title: &str, value: &str, color:
     }
 }
 
+/// Like a stat card, but shows the best on this sample plus a third line with the
+/// best across the whole language.
+fn render_best_card(
+    f: &mut Frame,
+    area: Rect,
+    sample_best: Option<PersonalBest>,
+    language_best: Option<PersonalBest>,
+    language_name: &str,
+) {
+    let value = match sample_best {
+        Some(best) => format!("{:.0} · {:.1}%", best.wpm, best.accuracy),
+        None => "—".to_string(),
+    };
+    render_stat_card(f, area, "🏆 BEST", &value, COLOR_GOLD);
+
+    let inner = Block::default().borders(Borders::ALL).inner(area);
+    if let (Some(best), true) = (language_best, inner.height >= 3) {
+        let lang_line = Line::from(Span::styled(
+            format!("{} {:.0} · {:.1}%", language_name, best.wpm, best.accuracy),
+            Style::default().fg(COLOR_GRAY),
+        ));
+        let lang_area = Rect {
+            x: inner.x,
+            y: inner.y + 2,
+            width: inner.width,
+            height: 1,
+        };
+        f.render_widget(
+            Paragraph::new(lang_line).alignment(Alignment::Center),
+            lang_area,
+        );
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::app::language::ALL;
+    use crate::app::language::{Language, ALL};
     use ratatui::backend::TestBackend;
     use ratatui::Terminal;
 
@@ -632,6 +1690,259 @@ mod tests {
         }
     }
 
+    #[test]
+    fn replays_show_their_speed_and_controls() {
+        use crate::app::replay::ReplayStatus;
+        let mut gs = GameState::new();
+        gs.select_menu_language();
+        gs.replay = Some(ReplayStatus {
+            speed: 2,
+            paused: false,
+        });
+        let out = render(&gs, 120, 30);
+        assert!(out.contains("REPLAY 2x"));
+        assert!(out.contains("speed"));
+
+        gs.replay = Some(ReplayStatus {
+            speed: 2,
+            paused: true,
+        });
+        assert!(render(&gs, 120, 30).contains("PAUSED"));
+    }
+
+    #[test]
+    fn the_ghost_shows_how_far_ahead_you_are() {
+        use crate::app::replay::Recording;
+        use crossterm::event::KeyCode;
+        let mut gs = GameState::new();
+        gs.load_code(Language::Rust, "let x = 1;", "");
+        gs.offer_ghost(&Recording {
+            timestamp: 0,
+            language: Language::Rust,
+            code: "let x = 1;".to_string(),
+            expected_output: String::new(),
+            wpm: 90.0,
+            accuracy: 100.0,
+            events: "let x".chars().map(|c| (0, KeyCode::Char(c))).collect(),
+            stop_on_error: false,
+        });
+        gs.handle_input('l');
+        let out = render(&gs, 120, 30);
+        assert!(out.contains("90 wpm"));
+        assert!(out.contains("-4"));
+    }
+
+    #[test]
+    fn sample_title_and_source_render() {
+        let mut gs = GameState::new();
+        gs.select_menu_language();
+        let title = gs.sample.title.to_string();
+        assert!(render(&gs, 120, 30).contains(&title));
+
+        gs.sample.source = Some("platform team".into());
+        assert!(render(&gs, 120, 30).contains("platform team"));
+    }
+
+    #[test]
+    fn the_sample_browser_keeps_the_selection_in_view() {
+        let mut gs = GameState::new();
+        gs.open_browser();
+        for _ in 0..40 {
+            gs.browser_down();
+        }
+        let title = gs.browser_selection().unwrap().title.to_string();
+        let screen = render(&gs, 100, 16);
+        assert!(screen
+            .lines()
+            .any(|line| line.contains('▸') && line.contains(&title)));
+        assert!(screen.contains(&format!("╭ {title} ")));
+
+        for c in "no such sample".chars() {
+            gs.browser_type(c);
+        }
+        assert!(render(&gs, 100, 16).contains("No samples match"));
+    }
+
+    #[test]
+    fn long_code_scrolls_to_keep_the_cursor_in_view() {
+        let code: String = (1..=30).map(|i| format!("line_{i} = {i}\n")).collect();
+        let mut library = crate::app::library::Library::default();
+        library.add_custom(
+            Language::Python,
+            crate::app::samples::Sample::from_code("long.py", code),
+        );
+        let mut gs = GameState::new();
+        gs.set_library(library);
+        gs.start_sample(Language::Python, 0);
+        assert!(render(&gs, 90, 26).contains("line_1 = 1"));
+
+        let typed: Vec<char> = gs
+            .current_code
+            .split("line_20")
+            .next()
+            .unwrap()
+            .chars()
+            .collect();
+        for c in typed {
+            gs.handle_input(c);
+        }
+        let screen = render(&gs, 90, 26);
+        assert!(!screen.contains("line_1 = 1"));
+        assert!(screen.contains("line_20 = 20"));
+    }
+
+    #[test]
+    fn long_wrapped_lines_keep_the_cursor_in_view() {
+        // Several rows each, of words so long that wrapping at word boundaries
+        // leaves half of every row empty
+        let code: String = (1..=12)
+            .map(|i| {
+                format!(
+                    "row{i} = [{}]\n",
+                    "abcdefghijklmnopqrstuvwxyzabc, ".repeat(8)
+                )
+            })
+            .collect();
+        let mut library = crate::app::library::Library::default();
+        library.add_custom(
+            Language::Python,
+            crate::app::samples::Sample::from_code("wide.py", code),
+        );
+        let mut gs = GameState::new();
+        gs.set_library(library);
+        gs.start_sample(Language::Python, 0);
+
+        let typed: Vec<char> = gs
+            .current_code
+            .split("row10 ")
+            .next()
+            .unwrap()
+            .chars()
+            .collect();
+        for c in typed {
+            gs.handle_input(c);
+        }
+        let screen = render(&gs, 70, 26);
+        assert!(screen.contains("row10 = ["));
+        assert!(!screen.contains("row1 = ["));
+    }
+
+    #[test]
+    fn personal_best_card_and_new_record_flag_render() {
+        let mut gs = GameState::new();
+        gs.select_menu_language();
+        assert!(render(&gs, 120, 30).contains("BEST"));
+
+        gs.sample_best = Some(PersonalBest {
+            wpm: 42.0,
+            accuracy: 99.5,
+        });
+        gs.language_best = gs.sample_best;
+        let out = render(&gs, 120, 30);
+        assert!(out.contains("42 · 99.5%"));
+        assert!(out.contains("Rust 42"));
+
+        gs.finish_game();
+        gs.new_record = true;
+        assert!(render(&gs, 160, 30).contains("NEW BEST"));
+    }
+
+    #[test]
+    fn stats_screen_renders_with_and_without_history() {
+        let mut gs = GameState::new();
+        gs.open_stats();
+        let out = render(&gs, 100, 40);
+        assert!(out.contains("Statistics"));
+        assert!(out.contains("No runs recorded yet"));
+
+        for (i, wpm) in [35.0, 52.0, 81.0].into_iter().enumerate() {
+            let run = RunRecord {
+                timestamp: 1_700_000_000 + i as u64 * 86_400,
+                language: gs.language,
+                sample_id: format!("s{i}"),
+                wpm,
+                accuracy: 99.0,
+                duration_secs: 60.0,
+                keystrokes: 300,
+                errors: 3,
+                ..Default::default()
+            };
+            gs.history.append(run).unwrap();
+        }
+        let out = render(&gs, 100, 40);
+        assert!(out.contains("LEGENDARY"));
+        assert!(out.contains("2023-11-14"));
+        assert!(out.contains("0h 03m"));
+        let _ = render(&gs, 40, 12);
+    }
+
+    #[test]
+    fn heatmap_renders_keys_and_confusions() {
+        let mut gs = GameState::new();
+        gs.open_heatmap();
+        assert!(render(&gs, 100, 40).contains("No mistakes recorded yet"));
+
+        let run = RunRecord {
+            language: gs.language,
+            confusions: vec![('|', '\\', 4), ('\n', ' ', 1)],
+            ..Default::default()
+        };
+        gs.history.append(run).unwrap();
+        let out = render(&gs, 100, 40);
+        assert!(out.contains("Key Heatmap"));
+        assert!(out.contains("4 misses"));
+        assert!(out.contains("×4"));
+        assert!(out.contains("Enter"));
+        let _ = render(&gs, 40, 12);
+    }
+
+    #[test]
+    fn slowest_sequences_render_on_completion_and_stats() {
+        let mut gs = GameState::new();
+        gs.select_menu_language();
+        gs.finish_game();
+        gs.run_ngrams = vec![("::".to_string(), 900.0, 2), ("->".to_string(), 300.0, 1)];
+        let out = render(&gs, 120, 40);
+        assert!(out.contains("Slowest"));
+        assert!(out.contains("::     450ms"));
+
+        let run = RunRecord {
+            language: gs.language,
+            ngrams: vec![("=>".to_string(), 1500.0, 3)],
+            ..Default::default()
+        };
+        gs.history.append(run).unwrap();
+        gs.open_stats();
+        assert!(render(&gs, 120, 40).contains("=>    500ms"));
+    }
+
+    #[test]
+    fn speed_timeline_renders_consistency_and_burst() {
+        let mut gs = GameState::new();
+        gs.select_menu_language();
+        gs.finish_game();
+        gs.wpm_timeline = vec![60.0, 60.0, 60.0, 84.0];
+        let out = render(&gs, 140, 40);
+        assert!(out.contains("Speed"));
+        assert!(out.contains("burst 84"));
+    }
+
+    #[test]
+    fn completion_bar_shows_raw_and_net_stats() {
+        let mut gs = GameState::new();
+        gs.select_menu_language();
+        gs.finish_game();
+        gs.wpm = 55.0;
+        gs.raw_wpm = 61.0;
+        gs.raw_accuracy = 91.5;
+        gs.corrected_errors = 4;
+        gs.uncorrected_errors = 1;
+        let out = render(&gs, 140, 40);
+        assert!(out.contains("55 wpm (raw 61)"));
+        assert!(out.contains("(raw 91.5%)"));
+        assert!(out.contains("4 fixed · 1 left"));
+    }
+
     #[test]
     fn renders_in_a_small_terminal_without_panicking() {
         let _ = render(&GameState::new(), 40, 12);
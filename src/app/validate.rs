@@ -0,0 +1,251 @@
+//! Checks for `code-typing validate`: things in a sample pack that make a
+//! sample untypeable or unfair. Loading normalizes some of them away (tabs,
+//! trailing whitespace, CRLF), but the pack itself should not rely on that,
+//! since what the author sees is then not what the player types.
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use super::language::Language;
+use super::library;
+use super::samples::Sample;
+
+/// One problem with a sample.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Problem {
+    /// 0-based line of the code it is on, or `None` for the sample as a whole.
+    pub code_line: Option<usize>,
+    pub message: String,
+}
+
+/// A [`Problem`] located in a pack file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// 1-based line of the pack file.
+    pub line: usize,
+    pub sample: String,
+    pub message: String,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}: {}", self.line, self.sample, self.message)
+    }
+}
+
+/// Check every sample of the pack at `path`. Repeats of a problem within one
+/// sample are reported once, on the first line it occurs, and each sample's
+/// problems are in line order.
+pub fn check_pack(path: &Path) -> io::Result<Vec<Diagnostic>> {
+    let text = fs::read_to_string(path)?;
+    let lines: Vec<&str> = text.lines().collect();
+    let mut diagnostics = Vec::new();
+    let mut from = 0;
+    for (language, sample) in library::read_pack(&text, path)? {
+        let code: Vec<&str> = sample.code.split('\n').collect();
+        let first = code.iter().position(|l| !l.trim().is_empty()).unwrap_or(0);
+        let start = find_line(&lines, from, &sample.title).unwrap_or(from);
+        let code_start = find_line(&lines, start, code[first].trim()).unwrap_or(start);
+        // Code in a multi-line string appears in the file line for line; code in
+        // an escaped string is reported where it starts.
+        let file_line = |i: usize| match (code_start + i).checked_sub(first) {
+            Some(line) if lines.get(line) == Some(&code[i]) => line,
+            _ => code_start,
+        };
+
+        let mut reported: Vec<(Diagnostic, usize)> = Vec::new();
+        for problem in check(language, &sample) {
+            if let Some((_, repeats)) = reported
+                .iter_mut()
+                .find(|(d, _)| d.message == problem.message)
+            {
+                *repeats += 1;
+                continue;
+            }
+            let line = problem.code_line.map_or(start, file_line);
+            let diagnostic = Diagnostic {
+                line: line + 1,
+                sample: sample.title.to_string(),
+                message: problem.message,
+            };
+            reported.push((diagnostic, 0));
+        }
+        reported.sort_by_key(|(d, _)| d.line);
+        for (mut diagnostic, repeats) in reported {
+            if repeats > 0 {
+                let s = if repeats == 1 { "" } else { "s" };
+                diagnostic.message = format!("{} (and {repeats} more line{s})", diagnostic.message);
+            }
+            diagnostics.push(diagnostic);
+        }
+        from = start + 1;
+    }
+    Ok(diagnostics)
+}
+
+/// First line at or after `from` containing `needle`.
+fn find_line(lines: &[&str], from: usize, needle: &str) -> Option<usize> {
+    (from..lines.len()).find(|&i| lines[i].contains(needle))
+}
+
+/// Everything wrong with `sample` as written.
+pub fn check(language: Language, sample: &Sample) -> Vec<Problem> {
+    let mut problems = Vec::new();
+    let mut on = |line: usize, message: String| {
+        problems.push(Problem {
+            code_line: Some(line),
+            message,
+        })
+    };
+
+    let indent_spaces = language.spec().indent_spaces;
+    // Whether the first indented line used a tab, to spot files that mix styles
+    let mut indent_style: Option<bool> = None;
+    for (i, line) in sample.code.split('\n').enumerate() {
+        if line.ends_with('\r') {
+            on(i, "CRLF line ending".to_string());
+        }
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        if line.ends_with([' ', '\t']) {
+            on(i, "trailing whitespace".to_string());
+        }
+        if line.contains('\t') {
+            on(
+                i,
+                format!("hard tab (the Tab key types {indent_spaces} spaces)"),
+            );
+        }
+        for c in line.chars() {
+            if !c.is_ascii() || (c.is_ascii_control() && c != '\t') {
+                on(
+                    i,
+                    format!("hard-to-type character {c:?} (U+{:04X})", c as u32),
+                );
+            }
+        }
+
+        let indent: String = line
+            .chars()
+            .take_while(|c| *c == ' ' || *c == '\t')
+            .collect();
+        if indent.is_empty() || line.trim().is_empty() {
+            continue;
+        }
+        let tabs = indent.contains('\t');
+        if tabs && indent.contains(' ') {
+            on(i, "indentation mixes tabs and spaces".to_string());
+        } else if *indent_style.get_or_insert(tabs) != tabs {
+            let (this, earlier) = if tabs {
+                ("tabs", "spaces")
+            } else {
+                ("spaces", "tabs")
+            };
+            on(
+                i,
+                format!("indented with {this}, earlier lines use {earlier}"),
+            );
+        }
+    }
+
+    let output = &sample.output;
+    let mut whole = |message: &str| {
+        problems.push(Problem {
+            code_line: None,
+            message: message.to_string(),
+        })
+    };
+    if sample.code.trim().is_empty() {
+        whole("no code to type");
+    }
+    if !output.is_empty() && output.trim().is_empty() {
+        whole("expected output is blank (leave it out to hide the output panel)");
+    } else if !output.is_empty() {
+        if output.contains('\r') {
+            whole("expected output has CRLF line endings");
+        }
+        if output.starts_with('\n') {
+            whole("expected output starts with a blank line");
+        }
+        if output.lines().any(|l| l.ends_with([' ', '\t'])) || output.ends_with('\n') {
+            whole("expected output has trailing whitespace");
+        }
+    }
+    problems
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::test_util::TempDir;
+
+    fn messages(code: &str, output: &str) -> Vec<(Option<usize>, String)> {
+        let mut sample = Sample::from_code("t", code);
+        sample.output = output.to_string().into();
+        check(Language::Go, &sample)
+            .into_iter()
+            .map(|p| (p.code_line, p.message))
+            .collect()
+    }
+
+    #[test]
+    fn clean_samples_pass() {
+        assert_eq!(messages("func f() {\n    g()\n}", "1\n2"), []);
+    }
+
+    #[test]
+    fn each_problem_is_found_on_its_line() {
+        let found = messages("a \r\n\tb\n  \tc\n    d\n\te\nf := \"é\"", " \n");
+        let lines: Vec<(Option<usize>, &str)> =
+            found.iter().map(|(l, m)| (*l, m.as_str())).collect();
+        assert_eq!(
+            lines,
+            [
+                (Some(0), "CRLF line ending"),
+                (Some(0), "trailing whitespace"),
+                (Some(1), "hard tab (the Tab key types 4 spaces)"),
+                (Some(2), "hard tab (the Tab key types 4 spaces)"),
+                (Some(2), "indentation mixes tabs and spaces"),
+                (Some(3), "indented with spaces, earlier lines use tabs"),
+                (Some(4), "hard tab (the Tab key types 4 spaces)"),
+                (Some(5), "hard-to-type character 'é' (U+00E9)"),
+                (
+                    None,
+                    "expected output is blank (leave it out to hide the output panel)"
+                ),
+            ]
+        );
+        assert_eq!(
+            messages("x", "\n1\n"),
+            [
+                (None, "expected output starts with a blank line".to_string()),
+                (None, "expected output has trailing whitespace".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn pack_diagnostics_point_at_file_lines() {
+        let dir = TempDir::new("validate");
+        let path = dir.join("pack.toml");
+        fs::write(
+            &path,
+            "language = \"go\"\n\n[[samples]]\ntitle = \"Clean\"\ncode = \"x := 1\"\n\n\
+             [[samples]]\ntitle = \"Tabs\"\ncode = '''\nfunc f() {\n\tg()  \n\th()\n}\n'''\n",
+        )
+        .unwrap();
+        let found: Vec<String> = check_pack(&path)
+            .unwrap()
+            .iter()
+            .map(Diagnostic::to_string)
+            .collect();
+        assert_eq!(
+            found,
+            [
+                "11: Tabs: trailing whitespace",
+                "11: Tabs: hard tab (the Tab key types 4 spaces) (and 1 more line)",
+            ]
+        );
+    }
+}
@@ -0,0 +1,238 @@
+//! Checks for `code-typing verify-output`: runs samples with the local
+//! toolchain and compares what they print with the output panel.
+//!
+//! Samples are written to a scratch directory, compiled if the language needs
+//! it, and run with a time limit. Built-in samples leave out the imports every
+//! program of their language needs, so a short prelude of standard includes is
+//! put in front of C and Rust code first. An expected output whose last line is
+//! `...` is truncated: the run must print at least the lines before it.
+
+use std::fs::{self, File};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use super::language::Language;
+use super::samples::Sample;
+
+/// How long a compiler or a sample may run before it is killed.
+const TIME_LIMIT: Duration = Duration::from_secs(20);
+
+/// How to run code of one language.
+pub struct Runner {
+    language: Language,
+    /// Command whose success shows the toolchain is installed.
+    probe: &'static [&'static str],
+    /// The file the code is written to.
+    file: &'static str,
+    /// Put before the code.
+    prelude: &'static str,
+    /// Command that compiles `file` into `bin`, if the language is compiled.
+    compile: &'static [&'static str],
+    /// Command that runs the program.
+    run: &'static [&'static str],
+}
+
+const RUNNERS: &[Runner] = &[
+    Runner {
+        language: Language::Rust,
+        probe: &["rustc", "--version"],
+        file: "main.rs",
+        prelude: "#![allow(warnings)]\nuse std::collections::*;\nuse std::hash::Hash;\n",
+        compile: &["rustc", "--edition", "2021", "-o", "bin", "main.rs"],
+        run: &["./bin"],
+    },
+    Runner {
+        language: Language::Python,
+        probe: &["python3", "--version"],
+        file: "main.py",
+        prelude: "",
+        compile: &[],
+        run: &["python3", "main.py"],
+    },
+    Runner {
+        language: Language::JavaScript,
+        probe: &["node", "--version"],
+        file: "main.js",
+        prelude: "",
+        compile: &[],
+        run: &["node", "main.js"],
+    },
+    Runner {
+        language: Language::C,
+        probe: &["gcc", "--version"],
+        file: "main.c",
+        prelude:
+            "#include <stdbool.h>\n#include <stdio.h>\n#include <stdlib.h>\n#include <string.h>\n",
+        compile: &["gcc", "-w", "-o", "bin", "main.c", "-lm"],
+        run: &["./bin"],
+    },
+    Runner {
+        language: Language::Go,
+        probe: &["go", "version"],
+        file: "main.go",
+        prelude: "",
+        compile: &["go", "build", "-o", "bin", "main.go"],
+        run: &["./bin"],
+    },
+];
+
+/// What running a sample showed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    /// It printed the expected output.
+    Matches,
+    /// It printed something else, given here.
+    Differs(String),
+    /// It did not compile, failed or timed out; the error output.
+    Failed(String),
+    /// It ran, but its output is marked nondeterministic and was not compared.
+    Ran,
+}
+
+/// The runner for `language`, if there is one.
+pub fn runner(language: Language) -> Option<&'static Runner> {
+    RUNNERS.iter().find(|r| r.language == language)
+}
+
+impl Runner {
+    /// The program the runner needs, for messages.
+    pub fn program(&self) -> &'static str {
+        self.probe[0]
+    }
+
+    pub fn is_installed(&self) -> bool {
+        Command::new(self.probe[0])
+            .args(&self.probe[1..])
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .is_ok_and(|s| s.success())
+    }
+
+    /// Compile and run `sample` in a fresh scratch directory.
+    pub fn verify(&self, sample: &Sample) -> io::Result<Outcome> {
+        let dir = scratch_dir()?;
+        let outcome = self.verify_in(&dir, sample);
+        let _ = fs::remove_dir_all(&dir);
+        outcome
+    }
+
+    fn verify_in(&self, dir: &Path, sample: &Sample) -> io::Result<Outcome> {
+        fs::write(
+            dir.join(self.file),
+            format!("{}{}", self.prelude, sample.code),
+        )?;
+        if !self.compile.is_empty() {
+            if let Err(error) = run(dir, self.compile)? {
+                return Ok(Outcome::Failed(error));
+            }
+        }
+        Ok(match run(dir, self.run)? {
+            Err(error) => Outcome::Failed(error),
+            Ok(_) if sample.nondeterministic => Outcome::Ran,
+            Ok(actual) if outputs_match(&sample.output, &actual) => Outcome::Matches,
+            Ok(actual) => Outcome::Differs(actual),
+        })
+    }
+}
+
+/// A new empty directory under the system temp dir.
+fn scratch_dir() -> io::Result<PathBuf> {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    static NEXT: AtomicUsize = AtomicUsize::new(0);
+    let n = NEXT.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("code-typing-verify-{}-{n}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir)?;
+    Ok(dir)
+}
+
+/// Run `command` in `dir`: its standard output if it succeeds in time,
+/// otherwise its error output (or standard output if that is empty).
+fn run(dir: &Path, command: &[&str]) -> io::Result<Result<String, String>> {
+    // Files rather than pipes, so a chatty program cannot block on a full pipe
+    let (stdout, stderr) = (dir.join(".stdout"), dir.join(".stderr"));
+    let mut child = Command::new(command[0])
+        .args(&command[1..])
+        .current_dir(dir)
+        .env_remove("RUST_BACKTRACE")
+        .stdin(Stdio::null())
+        .stdout(File::create(&stdout)?)
+        .stderr(File::create(&stderr)?)
+        .spawn()?;
+
+    let started = Instant::now();
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break Some(status);
+        }
+        if started.elapsed() > TIME_LIMIT {
+            let _ = child.kill();
+            let _ = child.wait();
+            break None;
+        }
+        thread::sleep(Duration::from_millis(10));
+    };
+
+    let output = String::from_utf8_lossy(&fs::read(stdout)?).into_owned();
+    let errors = String::from_utf8_lossy(&fs::read(stderr)?).into_owned();
+    Ok(match status {
+        Some(status) if status.success() => Ok(output),
+        Some(status) => {
+            let error = if errors.trim().is_empty() {
+                output
+            } else {
+                errors
+            };
+            Err(format!(
+                "`{}` failed ({status})\n{}",
+                command[0],
+                error.trim_end()
+            ))
+        }
+        None => Err(format!(
+            "`{}` did not finish within {} seconds",
+            command[0],
+            TIME_LIMIT.as_secs()
+        )),
+    })
+}
+
+/// Whether `actual` output is what `expected` shows, ignoring trailing
+/// whitespace. A last line of `...` in `expected` matches any further lines.
+pub fn outputs_match(expected: &str, actual: &str) -> bool {
+    let mut expected: Vec<&str> = expected.trim_end().lines().map(str::trim_end).collect();
+    let actual: Vec<&str> = actual.trim_end().lines().map(str::trim_end).collect();
+    if expected.last() == Some(&"...") {
+        expected.pop();
+        return actual.starts_with(&expected);
+    }
+    actual == expected
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn outputs_match_up_to_trailing_whitespace_and_truncation() {
+        assert!(outputs_match("1\n2", "1\n2\n"));
+        assert!(outputs_match("a \nb", "a\nb  \n\n"));
+        assert!(!outputs_match("1\n2", "1\n3\n"));
+        assert!(!outputs_match("1\n2", "1\n2\n3\n"));
+        assert!(outputs_match("1\n2\n...", "1\n2\n3\n4\n"));
+        assert!(!outputs_match("1\n2\n...", "1\n"));
+    }
+
+    #[test]
+    fn every_runner_has_a_program_and_a_file_of_its_language() {
+        for runner in RUNNERS {
+            assert!(!runner.run.is_empty());
+            let extension = runner.file.rsplit('.').next().unwrap();
+            assert_eq!(Language::from_extension(extension), Some(runner.language));
+        }
+    }
+}
@@ -0,0 +1,589 @@
+//! Command-line arguments and the non-interactive subcommands.
+//!
+//! With no arguments `code-typing` starts the game. Everything else is a small
+//! subcommand, so the parsing is done by hand rather than with a framework.
+
+use std::io::{self, Write};
+use std::path::PathBuf;
+
+use crate::app::history::{self, History, RunRecord};
+use crate::app::language::{Language, ALL};
+use crate::app::library;
+use crate::app::samples::Sample;
+use crate::app::validate;
+use crate::app::verify::{self, Outcome};
+
+pub const USAGE: &str = "\
+usage: code-typing [--snippets <dir>] [--pack <file>]... [--repo <path>]
+                   [--git-history <path> [--author <who>] [--since <when>]]
+                                       play, optionally on your own snippets (source files
+                                       and packs in <dir>), TOML/JSON sample packs,
+                                       functions extracted from a repository, or code
+                                       recently added to a git repository
+       code-typing [--lang <language>] <file>|-
+                                       type a single file, or whatever is piped to stdin
+                   [--window <lines>]  split loaded code longer than this (default 30, 0: never)
+                   [--seed <number>]   pick samples in the same order as any session with this seed
+       code-typing replay <file>       play back a saved recording
+       code-typing stats [--format csv|json] [--lang <language>] [--since YYYY-MM-DD]
+                                       print the run history
+       code-typing validate <pack>...  check sample packs for untypeable or unfair samples
+       code-typing verify-output [--lang <language>] [--pack <file>]...
+                                       run the built-in (or packs') samples with the local
+                                       toolchains and compare what they print";
+
+/// What the process was asked to do.
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Play(PlayOptions),
+    Replay(PathBuf),
+    Stats(StatsQuery),
+    Validate(Vec<PathBuf>),
+    VerifyOutput(VerifyQuery),
+    Help,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Csv,
+    Json,
+}
+
+/// Options for an interactive game.
+#[derive(Debug, Default, PartialEq)]
+pub struct PlayOptions {
+    /// Directory of source files and packs to use as samples.
+    pub snippets: Option<PathBuf>,
+    /// TOML/JSON sample packs.
+    pub packs: Vec<PathBuf>,
+    /// Repository to extract function-sized samples from.
+    pub repo: Option<PathBuf>,
+    /// Git repository whose recent changes become samples.
+    pub git_history: Option<PathBuf>,
+    /// Only changes by authors matching this (as `git log --author`).
+    pub author: Option<String>,
+    /// Only changes since this date (as `git log --since`).
+    pub since: Option<String>,
+    /// A single file to type, or `-` for stdin.
+    pub file: Option<PathBuf>,
+    /// Language of `file`, when its extension does not tell.
+    pub language: Option<Language>,
+    /// Split loaded samples longer than this many lines.
+    pub window: Option<usize>,
+    /// Seed for picking samples, so a session can be reproduced.
+    pub seed: Option<u64>,
+}
+
+/// Which runs `code-typing stats` prints, and how.
+#[derive(Debug, Default, PartialEq)]
+pub struct StatsQuery {
+    pub format: Format,
+    pub language: Option<Language>,
+    /// Only runs completed at or after this Unix timestamp.
+    pub since: Option<u64>,
+}
+
+/// Which samples `code-typing verify-output` runs.
+#[derive(Debug, Default, PartialEq)]
+pub struct VerifyQuery {
+    pub language: Option<Language>,
+    /// Packs to check instead of the built-in samples.
+    pub packs: Vec<PathBuf>,
+}
+
+/// Parse the arguments that follow the program name.
+pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Command, String> {
+    let mut args = args.into_iter().peekable();
+    let command = match args.peek().map(String::as_str) {
+        None => Command::Play(PlayOptions::default()),
+        Some("-h" | "--help" | "help") => {
+            args.next();
+            Command::Help
+        }
+        Some("replay") => {
+            args.next();
+            let file = args.next().ok_or("replay: missing recording file")?;
+            Command::Replay(PathBuf::from(file))
+        }
+        Some("stats") => {
+            args.next();
+            Command::Stats(parse_stats(&mut args)?)
+        }
+        Some("validate") => {
+            args.next();
+            let packs: Vec<PathBuf> = args.by_ref().map(PathBuf::from).collect();
+            if packs.is_empty() {
+                return Err("validate: missing pack file".to_string());
+            }
+            Command::Validate(packs)
+        }
+        Some("verify-output") => {
+            args.next();
+            Command::VerifyOutput(parse_verify(&mut args)?)
+        }
+        // Anything else is a game: options, and perhaps a file to type.
+        Some(_) => Command::Play(parse_play(&mut args)?),
+    };
+    match args.next() {
+        Some(extra) => Err(format!("unexpected argument `{extra}`")),
+        None => Ok(command),
+    }
+}
+
+fn parse_play(args: &mut impl Iterator<Item = String>) -> Result<PlayOptions, String> {
+    let mut options = PlayOptions::default();
+    while let Some((flag, mut value)) = next_flag(args) {
+        match flag.as_str() {
+            "--snippets" => options.snippets = Some(PathBuf::from(value()?)),
+            "--pack" => options.packs.push(PathBuf::from(value()?)),
+            "--repo" => options.repo = Some(PathBuf::from(value()?)),
+            "--git-history" => options.git_history = Some(PathBuf::from(value()?)),
+            "--author" => options.author = Some(value()?),
+            "--since" => options.since = Some(value()?),
+            "--window" => {
+                let lines = value()?;
+                options.window =
+                    Some(lines.parse().map_err(|_| {
+                        format!("invalid window `{lines}` (use a number of lines)")
+                    })?);
+            }
+            "--seed" => {
+                let seed = value()?;
+                options.seed = Some(
+                    seed.parse()
+                        .map_err(|_| format!("invalid seed `{seed}` (use a whole number)"))?,
+                );
+            }
+            "--lang" => {
+                let name = value()?;
+                options.language =
+                    Some(Language::from_name(&name).ok_or(format!("unknown language `{name}`"))?);
+            }
+            file if file == "-" || !file.starts_with('-') => {
+                if options.file.is_some() {
+                    return Err(format!("unexpected argument `{file}`"));
+                }
+                options.file = Some(PathBuf::from(file));
+            }
+            _ => return Err(format!("unknown option `{flag}`")),
+        }
+    }
+    if options.file.is_none() && options.language.is_some() {
+        return Err("--lang needs a file or `-` to type".to_string());
+    }
+    if options.git_history.is_none() && (options.author.is_some() || options.since.is_some()) {
+        return Err("--author and --since need --git-history".to_string());
+    }
+    Ok(options)
+}
+
+fn parse_stats(args: &mut impl Iterator<Item = String>) -> Result<StatsQuery, String> {
+    let mut query = StatsQuery::default();
+    while let Some((flag, mut value)) = next_flag(args) {
+        match flag.as_str() {
+            "--format" => {
+                query.format = match value()?.as_str() {
+                    "csv" => Format::Csv,
+                    "json" => Format::Json,
+                    other => return Err(format!("unknown format `{other}` (use csv or json)")),
+                }
+            }
+            "--lang" => {
+                let name = value()?;
+                query.language =
+                    Some(Language::from_name(&name).ok_or(format!("unknown language `{name}`"))?);
+            }
+            "--since" => {
+                let date = value()?;
+                query.since = Some(
+                    history::parse_date(&date)
+                        .ok_or(format!("invalid date `{date}` (use YYYY-MM-DD)"))?,
+                );
+            }
+            _ => return Err(format!("unknown option `{flag}`")),
+        }
+    }
+    Ok(query)
+}
+
+fn parse_verify(args: &mut impl Iterator<Item = String>) -> Result<VerifyQuery, String> {
+    let mut query = VerifyQuery::default();
+    while let Some((flag, mut value)) = next_flag(args) {
+        match flag.as_str() {
+            "--lang" => {
+                let name = value()?;
+                query.language =
+                    Some(Language::from_name(&name).ok_or(format!("unknown language `{name}`"))?);
+            }
+            "--pack" => query.packs.push(PathBuf::from(value()?)),
+            _ => return Err(format!("unknown option `{flag}`")),
+        }
+    }
+    Ok(query)
+}
+
+/// Split off the next `--flag value` or `--flag=value`, returning the flag and
+/// a closure that yields its value (taking the next argument if needed).
+fn next_flag<'a>(
+    args: &'a mut impl Iterator<Item = String>,
+) -> Option<(String, impl FnMut() -> Result<String, String> + 'a)> {
+    let arg = args.next()?;
+    let split = arg.split_once('=').filter(|_| arg.starts_with("--"));
+    let (flag, mut inline) = match split {
+        Some((flag, value)) => (flag.to_string(), Some(value.to_string())),
+        None => (arg, None),
+    };
+    let name = flag.clone();
+    let value = move || {
+        inline
+            .take()
+            .or_else(|| args.next())
+            .ok_or_else(|| format!("{name} needs a value"))
+    };
+    Some((flag, value))
+}
+
+/// Report the problems in each pack to `out` as `path:line: sample: problem`,
+/// returning whether every pack was readable and clean.
+pub fn validate_packs(packs: &[PathBuf], out: &mut impl Write) -> io::Result<bool> {
+    let mut clean = true;
+    for pack in packs {
+        match validate::check_pack(pack) {
+            Ok(diagnostics) => {
+                for diagnostic in &diagnostics {
+                    writeln!(out, "{}:{diagnostic}", pack.display())?;
+                }
+                clean &= diagnostics.is_empty();
+            }
+            Err(e) => {
+                writeln!(out, "{}: {e}", pack.display())?;
+                clean = false;
+            }
+        }
+    }
+    Ok(clean)
+}
+
+/// Run the samples with expected output that `query` selects and report to
+/// `out` how each went, returning whether none printed the wrong thing or
+/// failed. Languages without a runner or toolchain are skipped.
+pub fn verify_outputs(query: &VerifyQuery, out: &mut impl Write) -> io::Result<bool> {
+    let mut samples: Vec<(Language, Sample)> = Vec::new();
+    if query.packs.is_empty() {
+        for &language in ALL {
+            let builtin = language.spec().samples.iter().cloned();
+            samples.extend(builtin.map(|s| (language, s)));
+        }
+    }
+    for pack in &query.packs {
+        let loaded = library::load_pack(pack).map_err(|e| {
+            io::Error::new(e.kind(), format!("could not load {}: {e}", pack.display()))
+        })?;
+        samples.extend(loaded);
+    }
+    samples.retain(|(l, s)| {
+        !s.output.is_empty() && (query.language.is_none() || query.language == Some(*l))
+    });
+
+    let (mut matched, mut wrong, mut unchecked, mut skipped) = (0, 0, 0, 0);
+    for &language in ALL {
+        let of_language: Vec<&Sample> = samples
+            .iter()
+            .filter(|(l, _)| *l == language)
+            .map(|(_, s)| s)
+            .collect();
+        if of_language.is_empty() {
+            continue;
+        }
+        let runner = match verify::runner(language) {
+            Some(runner) if runner.is_installed() => runner,
+            found => {
+                let why = match found {
+                    Some(runner) => format!("`{}` is not installed", runner.program()),
+                    None => "no runner".to_string(),
+                };
+                let n = of_language.len();
+                let s = if n == 1 { "" } else { "s" };
+                writeln!(out, "skipped  {}: {why} ({n} sample{s})", language.name())?;
+                skipped += n;
+                continue;
+            }
+        };
+        for sample in of_language {
+            let name = format!("{} · {}", language.name(), sample.title);
+            match runner.verify(sample)? {
+                Outcome::Matches => {
+                    matched += 1;
+                    writeln!(out, "ok       {name}")?;
+                }
+                Outcome::Ran => {
+                    unchecked += 1;
+                    writeln!(out, "ran      {name} (nondeterministic, not compared)")?;
+                }
+                Outcome::Differs(actual) => {
+                    wrong += 1;
+                    writeln!(out, "DIFFERS  {name}")?;
+                    write_indented(out, "expected", &sample.output)?;
+                    write_indented(out, "actual", &actual)?;
+                }
+                Outcome::Failed(error) => {
+                    wrong += 1;
+                    writeln!(out, "FAILED   {name}")?;
+                    write_indented(out, "error", &error)?;
+                }
+            }
+        }
+    }
+    writeln!(
+        out,
+        "\n{matched} matched, {wrong} wrong, {unchecked} not compared, {skipped} skipped"
+    )?;
+    Ok(wrong == 0)
+}
+
+/// Write the first lines of `text` under a `label:` line, indented.
+fn write_indented(out: &mut impl Write, label: &str, text: &str) -> io::Result<()> {
+    const MAX_LINES: usize = 12;
+    writeln!(out, "    {label}:")?;
+    let lines: Vec<&str> = text.trim_end().lines().collect();
+    for line in lines.iter().take(MAX_LINES) {
+        writeln!(out, "        {line}")?;
+    }
+    if lines.len() > MAX_LINES {
+        writeln!(out, "        … {} more lines", lines.len() - MAX_LINES)?;
+    }
+    Ok(())
+}
+
+/// Write the runs matching `query` to `out`, oldest first.
+pub fn export_stats(history: &History, query: &StatsQuery, out: &mut impl Write) -> io::Result<()> {
+    let runs: Vec<&RunRecord> = history
+        .runs
+        .iter()
+        .filter(|r| match query.language {
+            Some(l) => r.language == l,
+            None => true,
+        })
+        .filter(|r| r.timestamp >= query.since.unwrap_or(0))
+        .collect();
+
+    match query.format {
+        Format::Json => {
+            serde_json::to_writer_pretty(&mut *out, &runs)?;
+            writeln!(out)
+        }
+        Format::Csv => {
+            writeln!(
+                out,
+                "date,timestamp,language,sample_id,wpm,accuracy,raw_wpm,raw_accuracy,\
+                 duration_secs,keystrokes,errors,corrected_errors,uncorrected_errors,\
+                 consistency,burst_wpm,mode,streak"
+            )?;
+            for r in runs {
+                writeln!(
+                    out,
+                    "{},{},{},{},{:.2},{:.2},{:.2},{:.2},{:.2},{},{},{},{},{:.2},{:.2},{},{}",
+                    history::format_date(r.timestamp),
+                    r.timestamp,
+                    csv_field(r.language.name()),
+                    csv_field(&r.sample_id),
+                    r.wpm,
+                    r.accuracy,
+                    r.raw_wpm,
+                    r.raw_accuracy,
+                    r.duration_secs,
+                    r.keystrokes,
+                    r.errors,
+                    r.corrected_errors,
+                    r.uncorrected_errors,
+                    r.consistency,
+                    r.burst_wpm,
+                    csv_field(&r.mode.name()),
+                    r.streak.map_or(String::new(), |s| s.to_string()),
+                )?;
+            }
+            Ok(())
+        }
+    }
+}
+
+/// `field` as a CSV field: quoted, with quotes doubled, if it holds a comma,
+/// quote or line break.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::mode::Mode;
+
+    fn parse_str(args: &str) -> Result<Command, String> {
+        parse(args.split_whitespace().map(String::from))
+    }
+
+    #[test]
+    fn commands_parse() {
+        assert_eq!(parse_str(""), Ok(Command::Play(PlayOptions::default())));
+        assert_eq!(
+            parse_str("--snippets=team --pack a.toml --pack b.json"),
+            Ok(Command::Play(PlayOptions {
+                snippets: Some(PathBuf::from("team")),
+                packs: vec![PathBuf::from("a.toml"), PathBuf::from("b.json")],
+                ..Default::default()
+            }))
+        );
+        assert_eq!(
+            parse_str("--git-history . --author=ada --since 2.weeks"),
+            Ok(Command::Play(PlayOptions {
+                git_history: Some(PathBuf::from(".")),
+                author: Some("ada".to_string()),
+                since: Some("2.weeks".to_string()),
+                ..Default::default()
+            }))
+        );
+        assert!(parse_str("--since 2.weeks").is_err());
+        assert_eq!(
+            parse_str("--lang python -"),
+            Ok(Command::Play(PlayOptions {
+                file: Some(PathBuf::from("-")),
+                language: Some(Language::Python),
+                ..Default::default()
+            }))
+        );
+        assert_eq!(
+            parse_str("a=b.rs"),
+            Ok(Command::Play(PlayOptions {
+                file: Some(PathBuf::from("a=b.rs")),
+                ..Default::default()
+            }))
+        );
+        assert!(parse_str("--lang rust").is_err());
+        assert_eq!(
+            parse_str("--window 12 --repo ."),
+            Ok(Command::Play(PlayOptions {
+                repo: Some(PathBuf::from(".")),
+                window: Some(12),
+                ..Default::default()
+            }))
+        );
+        assert!(parse_str("--window many").is_err());
+        assert_eq!(
+            parse_str("--seed 42"),
+            Ok(Command::Play(PlayOptions {
+                seed: Some(42),
+                ..Default::default()
+            }))
+        );
+        assert!(parse_str("--seed -1").is_err());
+        assert!(parse_str("a.rs b.rs").is_err());
+        assert_eq!(parse_str("--help"), Ok(Command::Help));
+        assert_eq!(
+            parse_str("replay run.json"),
+            Ok(Command::Replay(PathBuf::from("run.json")))
+        );
+        assert_eq!(
+            parse_str("stats --format json --lang=go --since 1970-01-02"),
+            Ok(Command::Stats(StatsQuery {
+                format: Format::Json,
+                language: Some(Language::Go),
+                since: Some(86_400),
+            }))
+        );
+        assert!(parse_str("stats --format xml").is_err());
+        assert!(parse_str("stats --lang").is_err());
+        assert!(parse_str("replay").is_err());
+        assert_eq!(
+            parse_str("validate a.toml b.json"),
+            Ok(Command::Validate(vec![
+                PathBuf::from("a.toml"),
+                PathBuf::from("b.json")
+            ]))
+        );
+        assert!(parse_str("validate").is_err());
+        assert_eq!(
+            parse_str("verify-output --lang rust --pack team.toml"),
+            Ok(Command::VerifyOutput(VerifyQuery {
+                language: Some(Language::Rust),
+                packs: vec![PathBuf::from("team.toml")],
+            }))
+        );
+        assert!(parse_str("verify-output rust").is_err());
+        assert!(parse_str("--bogus").is_err());
+    }
+
+    #[test]
+    fn csv_export_filters_runs() {
+        let mut history = History::default();
+        for (timestamp, language) in [
+            (0, Language::Rust),
+            (86_400, Language::Rust),
+            (86_400, Language::Go),
+        ] {
+            history
+                .append(RunRecord {
+                    timestamp,
+                    language,
+                    sample_id: "abc".to_string(),
+                    wpm: 50.0,
+                    accuracy: 97.5,
+                    ..Default::default()
+                })
+                .unwrap();
+        }
+        let query = StatsQuery {
+            language: Some(Language::Rust),
+            since: Some(86_400),
+            ..Default::default()
+        };
+        let mut out = Vec::new();
+        export_stats(&history, &query, &mut out).unwrap();
+        let csv = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("date,timestamp,language"));
+        assert!(lines[1].starts_with("1970-01-02,86400,Rust,abc,50.00,97.50,"));
+        assert!(lines[1].ends_with(",single sample,"));
+    }
+
+    #[test]
+    fn csv_export_quotes_fields_and_includes_streaks() {
+        let mut history = History::default();
+        history
+            .append(RunRecord {
+                sample_id: "a,\"b\"".to_string(),
+                mode: Mode::SuddenDeath,
+                streak: Some(42),
+                ..Default::default()
+            })
+            .unwrap();
+        let mut out = Vec::new();
+        export_stats(&history, &StatsQuery::default(), &mut out).unwrap();
+        let csv = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert!(lines[0].ends_with(",mode,streak"));
+        assert!(lines[1].contains(",Rust,\"a,\"\"b\"\"\",0.00,"));
+        assert!(lines[1].ends_with(",sudden death,42"));
+        assert_eq!(csv_field("plain"), "plain");
+        assert_eq!(csv_field("two\nlines"), "\"two\nlines\"");
+    }
+
+    #[test]
+    fn json_export_is_an_array_of_runs() {
+        let mut history = History::default();
+        history.append(RunRecord::default()).unwrap();
+        let mut out = Vec::new();
+        let query = StatsQuery {
+            format: Format::Json,
+            ..Default::default()
+        };
+        export_stats(&history, &query, &mut out).unwrap();
+        let runs: Vec<RunRecord> = serde_json::from_slice(&out).unwrap();
+        assert_eq!(runs, vec![RunRecord::default()]);
+    }
+}
@@ -1,4 +1,5 @@
 mod app;
+mod cli;
 
 use crossterm::{
     execute,
@@ -9,7 +10,13 @@ use ratatui::Terminal;
 use std::error::Error;
 use std::io;
 
-use app::Game;
+use app::history::History;
+use app::language::Language;
+use app::library::{self, Library};
+use app::replay::{Ghosts, Recording};
+use app::samples::Sample;
+use app::{Game, GameState};
+use cli::{Command, PlayOptions};
 
 /// Restore terminal to normal state
 fn restore_terminal() {
@@ -17,7 +24,104 @@ fn restore_terminal() {
     let _ = execute!(io::stdout(), LeaveAlternateScreen);
 }
 
+/// The samples asked for on the command line, or `None` for the built-in ones.
+/// A single `file` comes first, so its first part is sample 0 of its language.
+fn load_library(
+    options: &PlayOptions,
+    file: Option<(Language, Sample)>,
+) -> Result<Option<Library>, String> {
+    if file.is_none()
+        && options.snippets.is_none()
+        && options.packs.is_empty()
+        && options.repo.is_none()
+        && options.git_history.is_none()
+    {
+        return Ok(None);
+    }
+    let mut library = Library::default();
+    if let Some(lines) = options.window {
+        library.set_window(lines);
+    }
+    if let Some((language, sample)) = file {
+        library.add_custom(language, sample);
+    }
+    if let Some(dir) = &options.snippets {
+        library
+            .add_snippets(dir)
+            .map_err(|e| format!("could not load snippets from {}: {e}", dir.display()))?;
+    }
+    for pack in &options.packs {
+        library
+            .add_pack(pack)
+            .map_err(|e| format!("could not load pack {}: {e}", pack.display()))?;
+    }
+    if let Some(root) = &options.repo {
+        library
+            .add_repo(root)
+            .map_err(|e| format!("could not extract samples from {}: {e}", root.display()))?;
+    }
+    if let Some(root) = &options.git_history {
+        library
+            .add_git_history(root, options.author.as_deref(), options.since.as_deref())
+            .map_err(|e| format!("could not read the history of {}: {e}", root.display()))?;
+    }
+    Ok(Some(library))
+}
+
 fn main() -> Result<(), Box<dyn Error>> {
+    let command = cli::parse(std::env::args().skip(1)).unwrap_or_else(|e| {
+        eprintln!("error: {e}\n\n{}", cli::USAGE);
+        std::process::exit(2);
+    });
+
+    // Non-interactive commands never touch the terminal
+    let (recording, library, file_language, seed) = match command {
+        Command::Help => {
+            println!("{}", cli::USAGE);
+            return Ok(());
+        }
+        Command::Stats(query) => {
+            let history = History::load()?;
+            cli::export_stats(&history, &query, &mut io::stdout().lock())?;
+            return Ok(());
+        }
+        Command::Validate(packs) => {
+            if !cli::validate_packs(&packs, &mut io::stdout().lock())? {
+                std::process::exit(1);
+            }
+            return Ok(());
+        }
+        Command::VerifyOutput(query) => {
+            if !cli::verify_outputs(&query, &mut io::stdout().lock())? {
+                std::process::exit(1);
+            }
+            return Ok(());
+        }
+        Command::Replay(path) => {
+            let recording = Recording::load(&path)
+                .map_err(|e| format!("could not read recording {}: {e}", path.display()))?;
+            (Some(recording), None, None, None)
+        }
+        Command::Play(options) => {
+            // Piped input is read here, before raw mode; keys then come from the TTY
+            let file = match &options.file {
+                Some(path) => Some(
+                    library::load_file(path, options.language)
+                        .map_err(|e| format!("could not read {}: {e}", path.display()))?,
+                ),
+                None => None,
+            };
+            let language = file.as_ref().map(|(language, _)| *language);
+            (None, load_library(&options, file)?, language, options.seed)
+        }
+    };
+
+    // Load run history before touching the terminal so problems can be reported normally
+    let history = History::load().unwrap_or_else(|e| {
+        eprintln!("warning: could not read run history ({e}); this session will not be saved");
+        History::default()
+    });
+
     // Set up panic hook to restore terminal on panic
     let original_hook = std::panic::take_hook();
     std::panic::set_hook(Box::new(move |panic_info| {
@@ -33,8 +137,22 @@ fn main() -> Result<(), Box<dyn Error>> {
     let terminal = Terminal::new(backend)?;
 
     // Run the game
-    let mut game = Game::new(terminal);
-    let result = game.run();
+    let mut game_state = GameState::with_history(history);
+    game_state.ghosts = Ghosts::load();
+    if let Some(library) = library {
+        game_state.set_library(library);
+    }
+    if let Some(seed) = seed {
+        game_state.set_seed(seed);
+    }
+    if let Some(language) = file_language {
+        game_state.start_sample(language, 0);
+    }
+    let mut game = Game::new(terminal, game_state);
+    let result = match &recording {
+        Some(recording) => game.replay(recording),
+        None => game.run(),
+    };
 
     // Restore terminal
     restore_terminal();